@@ -8,6 +8,7 @@ use ezmenulib::{
         event::Event,
         *,
     },
+    tui_fields,
 };
 use tui::{backend::CrosstermBackend, Terminal};
 
@@ -29,12 +30,12 @@ fn wait() -> MenuResult {
 }
 
 fn main() -> MenuResult {
-    let mut menu = TuiMenu::<Crossterm>::try_from(&[
+    let mut menu = TuiMenu::<Crossterm>::try_from(tui_fields![
         ("first", TuiKind::Map(&first)),
         ("second", TuiKind::Quit),
         (
             "third",
-            TuiKind::Parent(&[
+            TuiKind::Parent(tui_fields![
                 ("fourth", TuiKind::Quit),
                 ("fifth", TuiKind::Quit),
                 ("go back", TuiKind::Back(1)),
@@ -3,13 +3,51 @@
 #[cfg(test)]
 mod tests;
 
-use crate::prelude::*;
-use crate::utils::*;
+mod defaults;
+mod kind;
+mod picker;
+#[cfg(feature = "keyring")]
+mod secret;
+mod selected;
+mod session;
+mod spinner;
+mod style;
+mod table;
+mod theme;
+mod written;
+
+#[cfg(feature = "toml")]
+pub use crate::field::defaults::TomlDefaults;
+pub use crate::field::defaults::{DefaultsProvider, EnvDefaults};
+pub use crate::field::kind::{
+    Binding, DynField, DynFields, Field, Fields, Kind, NavBinding, Navigation, ValueBinding,
+};
+pub use crate::field::picker::FilePicker;
+#[cfg(feature = "keyring")]
+#[cfg_attr(nightly, doc(cfg(feature = "keyring")))]
+pub use crate::field::secret::Secret;
+pub use crate::field::selected::{Selectable, Selected};
+pub use crate::field::session::{PromptSession, Step};
+pub use crate::field::spinner::Spinner;
+pub use crate::field::style::{Color, Style};
+pub use crate::field::table::TableSelect;
+pub use crate::field::theme::{Fancy, Minimal, Plain, Theme};
+pub use crate::field::written::Written;
+
+/// The previous name of the [`Selected`] struct.
+#[deprecated(since = "0.3.0", note = "renamed to `Selected`")]
+pub type SelectMenu<'a, T, const N: usize = 0> = Selected<'a, T, N>;
+
+/// The previous name of the [`Written`] struct.
+#[deprecated(since = "0.3.0", note = "renamed to `Written`")]
+pub type ValueField<'a> = Written<'a>;
+
+/// The previous name of the [`Format`] struct.
+#[deprecated(since = "0.3.0", note = "renamed to `Format`")]
+pub type ValueFieldFormatting<'a> = Format<'a>;
+
 use crate::DEFAULT_FMT;
-use std::env;
-use std::fmt::{self, Display, Formatter};
-use std::io::{BufRead, Write};
-use std::str::FromStr;
+use std::fmt::Display;
 
 /// Builds the associated functions of the [`Format`] struct
 /// according to its fields.
@@ -39,6 +77,26 @@ macro_rules! impl_fmt {
                 )*}
             }
 
+            /// Returns the specifications of the merged version between `self` and `r`,
+            /// one per line, each followed by the level it comes from.
+            ///
+            /// The level is `field` if the specification comes from `self`, `global`
+            /// if it comes from `r`, and `default` if both correspond to the default one.
+            pub(crate) fn explain(&self, r: &Format<'a>) -> String {
+                let mut s = String::new();
+                $(
+                let (value, level) = if self.$i != DEFAULT_FMT.$i {
+                    (&self.$i, "field")
+                } else if r.$i != DEFAULT_FMT.$i {
+                    (&r.$i, "global")
+                } else {
+                    (&self.$i, "default")
+                };
+                s.push_str(&format!("{}: {:?} ({})\n", stringify!($i), value, level));
+                )*
+                s
+            }
+
             // Constructors
             $(
             $(#[doc = $doc])*
@@ -92,7 +150,7 @@ impl_fmt!(
     /// Defines if it displays the default value or not (`true` by default).
     ///
     /// If an example is provided in the current written field,
-    /// the latter will always be displayed.
+    /// the latter is displayed according to the `show_example` specification.
     suffix: &'a str,
     /// Sets the prefix of the formatting (`">> "` by default).
     ///
@@ -104,8 +162,60 @@ impl_fmt!(
     /// but only the suffix. Otherwise, because it is on the same line, it will display
     /// the whole message again.
     ///
-    /// For selectable fields, if `new_line` format specification is set as `false`,
-    /// it will use the default suffix, and always use a line break, for more convenience.
+    /// For selectable fields, if it is set to `false`, the suffix is displayed on the line
+    /// of the message, and the values are listed below. The cursor is moved back with
+    /// ANSI escape codes, so it only applies if the stream is [colored](crate::menu::MenuStream::colored)
+    /// and the `"color"` feature is enabled.
+    /// Otherwise, it uses the default suffix, and always uses a line break.
+    max_label_width: Option<usize>,
+    /// Defines the maximum width of the field messages in a list (`None` by default).
+    ///
+    /// The messages longer than this width are truncated and end with `…`,
+    /// so they don't break the layout of the list.
+    page_size: Option<usize>,
+    /// Defines the maximum amount of fields displayed at once in a list (`None` by default).
+    ///
+    /// If a list contains more fields, it is split into pages, and the user can
+    /// enter `n` or `p` to display the next or the previous page.
+    style: Style,
+    /// Defines the colors of the prompts ([`Style::PLAIN`] by default).
+    ///
+    /// The style is only applied if the `"color"` feature is enabled, and if the stream
+    /// is colored (see [`MenuStream::colored`](crate::menu::MenuStream::colored)).
+    verbosity: Verbosity,
+    /// Defines the amount of details displayed with the fields ([`Verbosity::Normal`] by default).
+    indent: usize,
+    /// Defines the amount of spaces displayed at the beginning of each line of a list
+    /// (`0` by default).
+    start_index: usize,
+    /// Defines the index of the first field of a list (`1` by default).
+    ///
+    /// It only applies to the [numeric](IndexStyle::Numeric) indexes. If it is `0`,
+    /// the `0` hotkey of the [zero field](crate::menu::RawMenu::zero) of a menu
+    /// takes precedence over the first field.
+    index_style: IndexStyle,
+    /// Defines how the indexes of a list are displayed ([`IndexStyle::Numeric`] by default).
+    ///
+    /// The user enters the index in the same style to select a field.
+    optional_label: &'a str,
+    /// Defines the label marking the optional fields (`"optional"` by default).
+    ///
+    /// It is displayed between parentheses after the message, like `(optional)`.
+    default_label: &'a str,
+    /// Defines the label of the default values (`"default"` by default).
+    ///
+    /// It is displayed before the default value of a written field, like `(default: 18)`,
+    /// and between parentheses after the default field of a list, like `(default)`.
+    example_label: &'a str,
+    /// Defines the label of the examples (`"example"` by default).
+    ///
+    /// It is displayed before the example of a written field, like `(example: 18)`.
+    show_example: bool,
+    /// Defines if it displays the example of the written fields or not (`true` by default).
+    show_optional: bool,
+    /// Defines if it marks the optional fields or not (`true` by default).
+    ///
+    /// The mark is displayed like `(optional)` after the message (see `optional_label`).
 );
 
 /// Default formatting for a field is `"--> "` as a chip and `">> "` as prefix.
@@ -121,869 +231,120 @@ impl<'a> Default for Format<'a> {
     }
 }
 
-/// Defines the behavior for a written value provided by the user.
-///
-/// Like the [selected](Selected) values, it contains its own [format](Format),
-/// and it can be inherited, saving the custom format specifications.
-///
-/// It displays the message, with a given example and default value if it is provided
-/// (see [`Written::example`] or [`Written::default_value`] functions).
-///
-/// It provides functions to define how to retrieve the value from the user.
-/// You have to provide a mutable reference to a [`MenuStream`] to retrieve the value.
-///
-/// # Example
-///
-/// For a make-license CLI program for example, you can use it like below:
-///
-/// ```no_run
-/// use ezmenulib::prelude::*;
-///
-/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-/// let author: Vec<String> = Written::from("Give the authors of the license")
-///     .many_values(&mut MenuStream::default(), ", ")?;
-/// # Ok(()) }
-/// ```
-#[derive(Debug)]
-pub struct Written<'a> {
-    msg: &'a str,
-    /// The format of the written field value.
-    pub fmt: Format<'a>,
-    example: Option<&'a str>,
-    default: Option<String>,
-}
-
-impl<'a> From<&'a str> for Written<'a> {
-    fn from(msg: &'a str) -> Self {
-        Self {
-            msg,
-            fmt: Format::default(),
-            example: None,
-            default: None,
+impl Format<'_> {
+    /// Returns the given index with the indentation and the surroundings of the lists.
+    pub(crate) fn surround<T: Display>(&self, index: T) -> String {
+        format!(
+            "{:indent$}{}{}{}",
+            "",
+            self.left_sur,
+            index,
+            self.right_sur,
+            indent = self.indent
+        )
+    }
+
+    /// Returns the index of the field at the given position of a list, starting from `0`,
+    /// with the indentation and the surroundings of the lists.
+    pub(crate) fn index(&self, i: usize) -> String {
+        self.surround(self.index_style.label(i, self.start_index))
+    }
+
+    /// Returns the position of the field whose index is the given input, among `len` fields.
+    pub(crate) fn parse_index(&self, s: &str, len: usize) -> Option<usize> {
+        match self.index_style {
+            IndexStyle::Numeric => s
+                .parse::<usize>()
+                .ok()
+                .and_then(|n| n.checked_sub(self.start_index)),
+            style => (0..len).find(|i| style.label(*i, 0).eq_ignore_ascii_case(s)),
         }
+        .filter(|i| *i < len)
     }
 }
 
-impl Display for Written<'_> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        self.fmt_with(f, &self.fmt, false)?;
-        f.write_str(match self.fmt.line_brk {
-            true => "\n",
-            false => self.fmt.suffix,
-        })
-    }
-}
-
-/// Constructor methods defining how the field behaves
-impl<'a> Written<'a> {
-    /// Displays the message of the written field with a given prefix.
-    ///
-    /// This is used to prompt the written field with a given [`Format`]
-    /// (see [`Written::prompt_with`] function for example).
-    fn fmt_with<S: fmt::Write>(&self, s: &mut S, fmt: &Format<'_>, opt: bool) -> fmt::Result {
-        s.write_str(fmt.prefix)?;
-        s.write_str(self.msg)?;
-
-        // Field details
-        if opt || self.example.is_some() || self.default.is_some() {
-            s.write_str(" (")?;
-
-            // - Example
-            if let Some(e) = self.example {
-                write!(s, "example: {}", e)?;
-                if opt || self.fmt.show_default && self.default.is_some() {
-                    s.write_str(", ")?;
-                }
-            }
-
-            // - Default
-            match self.default {
-                Some(ref d) if self.fmt.show_default => write!(s, "default: {}", d)?,
-                _ => (),
-            }
-
-            // - Optional
-            if opt && self.default.is_none() {
-                s.write_str("optional")?;
-            }
-
-            s.write_str(")")?;
-        }
-
-        match fmt.line_brk {
-            true => s.write_char('\n'),
-            false => Ok(()),
-        }
-    }
-
-    fn first_line<R, W: Write>(
-        &self,
-        stream: &mut MenuStream<R, W>,
-        fmt: &Format<'_>,
-        opt: bool,
-    ) -> MenuResult {
-        if fmt.line_brk {
-            self.fmt_with(stream, fmt, opt)?;
-        }
-        Ok(())
-    }
-
-    /// Displays the second line according to the format, and returns the output
-    /// of the prompt.
-    fn prompt_line<R: BufRead, W: Write>(
-        &self,
-        stream: &mut MenuStream<R, W>,
-        fmt: &Format<'_>,
-        opt: bool,
-    ) -> MenuResult<String> {
-        if !fmt.line_brk {
-            self.fmt_with(stream, fmt, opt)?;
-        }
-
-        prompt(fmt.suffix, stream)
-    }
-
-    /// Gives a custom formatting for the written field.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// # use ezmenulib::prelude::*;
-    /// let w = Written::from("hello").format(Format::prefix("==> "));
-    /// ```
-    pub fn format(mut self, fmt: Format<'a>) -> Self {
-        self.fmt = fmt;
-        self
-    }
-
-    /// Gives the default value accepted by the field.
-    ///
-    /// If the value type is incorrect, the [`Written::prompt`] function and its variations
-    /// will panic at runtime.
-    ///
-    /// The default value and the example (see the [`example`](Written::example) method documentation)
-    /// will be displayed inside parenthesis according to its formatting (see [`Format`]
-    /// for more information).
-    pub fn default_value(mut self, default: &'a str) -> Self {
-        self.default = Some(default.to_owned());
-        self
-    }
-
-    /// Gives the default value of the field, passed by an environment variable.
-    ///
-    /// If the provided environment variable is incorrect, it will return an error
-    /// (See [`MenuError::EnvVar`] variant).
-    ///
-    /// If the value type of the variable is incorrect, the [`Written::prompt`] function
-    /// and its variations will panic at runtime.
-    ///
-    /// # Example
-    ///
-    /// ```no_run
-    /// # use ezmenulib::prelude::*;
-    /// # use std::error::Error;
-    /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// let user: String = Written::from("What is your name?")
-    ///     .default_env("USERNAME")?
-    ///     .prompt(&mut MenuStream::default())?;
-    /// # Ok(())
-    /// # }
-    /// ```
-    pub fn default_env(mut self, var: &'a str) -> MenuResult<Self> {
-        self.default = Some(env::var(var).map_err(|e| MenuError::EnvVar(var.to_owned(), e))?);
-        Ok(self)
-    }
-
-    /// Gives an example of correct value for the field.
-    ///
-    /// Obviously, it is better to give a correct value for the user as example,
-    /// but if the value is incorrect, it will only mislead the user,
-    /// and unlike the default value providing, the program will not panic at runtime
-    /// to emphasize the problem.
-    ///
-    /// The example will be shown inside parenthesis according to its formatting
-    /// (see [`Format`] for more information).
-    pub fn example(mut self, example: &'a str) -> Self {
-        self.example = Some(example);
-        self
-    }
-
-    /// Prompts the field once, using the given prefix.
-    ///
-    /// It checks the `line_brk` specification. If it is on `true`, the suffix is displayed
-    /// on a separate line, thus it will only display the suffix. Otherwise, it prints out
-    /// the whole message with the suffix.
-    fn prompt_once<R: BufRead, W: Write, T: FromStr>(
-        &self,
-        stream: &mut MenuStream<R, W>,
-        fmt: &Format<'_>,
-        opt: bool,
-    ) -> MenuResult<Option<T>> {
-        fn default_output<T: FromStr>(d: &str) -> T {
-            d.parse().unwrap_or_else(|_| default_failed::<T>(d))
-        }
-
-        let s = self.prompt_line(stream, fmt, opt)?;
-
-        if s.is_empty() {
-            return Ok(self.default.as_deref().map(default_output));
-        }
-
-        let out = s
-            .parse()
-            .ok()
-            .or_else(|| self.default.as_deref().map(default_output));
-
-        Ok(out)
-    }
-
-    /// Prompts the field and returns the input, or `None` if the input is incorrect,
-    /// using the given format.
-    ///
-    /// It uses the merged version between the format of the written field and the given format.
-    ///
-    /// It prompts the field once, and if the user entered a correct input,
-    /// it returns `Some(value)`, otherwise, it attempts to return the default value
-    /// (see [`Written::default_value`] or [`Written::default_env`]), and if there is no
-    /// default value, it returns `None`.
-    ///
-    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]).
-    ///
-    /// # Panics
-    ///
-    /// If the default value has an incorrect type, this function will panic.
-    pub fn optional_value_with<R, W, T>(
-        &self,
-        stream: &mut MenuStream<R, W>,
-        fmt: &Format<'_>,
-    ) -> MenuResult<Option<T>>
-    where
-        R: BufRead,
-        W: Write,
-        T: FromStr,
-    {
-        let fmt = self.fmt.merged(fmt);
-        self.first_line(stream, &fmt, true)?;
-        self.prompt_once(stream, &fmt, true)
-    }
-
-    /// Prompts the field and returns the input, or `None` if the input is incorrect.
-    ///
-    /// It prompts the field once, and if the user entered a correct input,
-    /// it returns `Some(value)`, otherwise, it attempts to return the default value
-    /// (see [`Written::default_value`] or [`Written::default_env`]), and if there is no
-    /// default value, it returns `None`.
-    ///
-    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]).
-    ///
-    /// # Panics
-    ///
-    /// If the default value has an incorrect type, this function will panic.
-    pub fn optional_value<R, W, T>(&self, stream: &mut MenuStream<R, W>) -> MenuResult<Option<T>>
-    where
-        R: BufRead,
-        W: Write,
-        T: FromStr,
-    {
-        self.optional_value_with(stream, &self.fmt)
-    }
-
-    /// Prompts the field and returns the inputs as a `Vec<T>` until the given
-    /// constraint is applied to all the values, using `sep` to split the input
-    /// into the output values, and using the given format.
-    ///
-    /// It uses the merged version between the format of the written field and the given format.
-    ///
-    /// After checking and parsing the values provided by the user, it calls the `til` function.
-    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
-    ///
-    /// # Panics
-    ///
-    /// If the default value has an incorrect type, this function will panic.
-    pub fn many_values_until_with<R, W, T, S, F>(
-        &self,
-        stream: &mut MenuStream<R, W>,
-        sep: S,
-        til: F,
-        fmt: &Format<'_>,
-    ) -> MenuResult<Vec<T>>
-    where
-        R: BufRead,
-        W: Write,
-        T: FromStr,
-        S: AsRef<str>,
-        F: Fn(&T) -> bool,
-    {
-        fn inner_prompt_once<R: BufRead, W: Write, T: FromStr>(
-            w: &Written<'_>,
-            stream: &mut MenuStream<R, W>,
-            sep: &str,
-            fmt: &Format<'_>,
-        ) -> MenuResult<Option<Vec<T>>> {
-            let s = w.prompt_line(stream, fmt, false)?;
-            let res: Result<Vec<T>, T::Err> = s.split(sep).map(T::from_str).collect();
-
-            Ok(res.ok().or_else(|| {
-                let default = w.default.as_ref()?;
-                let res: Result<Vec<T>, T::Err> = default.split(sep).map(T::from_str).collect();
-                Some(res.unwrap_or_else(|_| default_failed::<T>(default)))
-            }))
-        }
-
-        let fmt = self.fmt.merged(fmt);
-        self.first_line(stream, &fmt, false)?;
-        let s = sep.as_ref();
-
-        // Loops while incorrect input.
-        loop {
-            match inner_prompt_once(self, stream, s, &fmt)? {
-                Some(v) if v.iter().all(&til) => return Ok(v),
-                _ => continue,
-            }
-        }
-    }
-
-    /// Prompts the field and returns the inputs as a `Vec<T>` until the given
-    /// constraint is applied to all the values, using `sep` to split the input
-    /// into the output values.
-    ///
-    /// After checking and parsing the values provided by the user, it calls the `til` function.
-    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
-    ///
-    /// # Panics
-    ///
-    /// If the default value has an incorrect type, this function will panic.
-    pub fn many_values_until<R, W, T, S, F>(
-        &self,
-        stream: &mut MenuStream<R, W>,
-        sep: S,
-        til: F,
-    ) -> MenuResult<Vec<T>>
-    where
-        R: BufRead,
-        W: Write,
-        T: FromStr,
-        S: AsRef<str>,
-        F: Fn(&T) -> bool,
-    {
-        self.many_values_until_with(stream, sep, til, &self.fmt)
-    }
-
-    /// Prompts the field and returns the inputs as a `Vec<T>` using `sep` to split the input
-    /// into the output values, and using the given format.
-    ///
-    /// It uses the merged version between the format of the written field and the given format.
-    ///
-    /// After checking and parsing the values provided by the user, it calls the `til` function.
-    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
-    ///
-    /// # Panics
-    ///
-    /// If the default value has an incorrect type, this function will panic.
-    pub fn many_values_with<R, W, T, S>(
-        &self,
-        stream: &mut MenuStream<R, W>,
-        sep: S,
-        fmt: &Format<'_>,
-    ) -> MenuResult<Vec<T>>
-    where
-        R: BufRead,
-        W: Write,
-        T: FromStr,
-        S: AsRef<str>,
-    {
-        self.many_values_until_with(stream, sep, keep, fmt)
-    }
-
-    /// Prompts the field and returns the inputs as a `Vec<T>` using `sep` to split the input
-    /// into the output values.
-    ///
-    /// After checking and parsing the values provided by the user, it calls the `til` function.
-    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
-    ///
-    /// # Panics
-    ///
-    /// If the default value has an incorrect type, this function will panic.
-    pub fn many_values<R, W, T, S>(
-        &self,
-        stream: &mut MenuStream<R, W>,
-        sep: S,
-    ) -> MenuResult<Vec<T>>
-    where
-        R: BufRead,
-        W: Write,
-        T: FromStr,
-        S: AsRef<str>,
-    {
-        self.many_values_with(stream, sep, &self.fmt)
-    }
-
-    /// Prompts the field until the constraint is applied, using the given format.
-    ///
-    /// It uses the merged version between the format of the written field and the given format.
-    /// After checking and parsing the value provided by the user, it calls the `til` function.
-    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
-    ///
-    /// # Panic
-    ///
-    /// If the default value has an incorrect type, this function will panic.
-    pub fn prompt_until_with<R, W, T, F>(
-        &self,
-        stream: &mut MenuStream<R, W>,
-        til: F,
-        fmt: &Format<'a>,
-    ) -> MenuResult<T>
-    where
-        R: BufRead,
-        W: Write,
-        T: FromStr,
-        F: Fn(&T) -> bool,
-    {
-        let fmt = self.fmt.merged(fmt);
-        self.first_line(stream, &fmt, false)?;
-
-        // Loops while incorrect input.
-        loop {
-            match self.prompt_once(stream, &fmt, false)? {
-                Some(out) if til(&out) => return Ok(out),
-                _ => continue,
-            }
-        }
-    }
-
-    /// Prompts the field until the constraint is applied.
-    ///
-    /// After checking and parsing the value provided by the user, it calls the `til` function.
-    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
-    ///
-    /// # Panic
-    ///
-    /// If the default value has an incorrect type, this function will panic.
-    pub fn prompt_until<R, W, T, F>(&self, stream: &mut MenuStream<R, W>, til: F) -> MenuResult<T>
-    where
-        R: BufRead,
-        W: Write,
-        T: FromStr,
-        F: Fn(&T) -> bool,
-    {
-        self.prompt_until_with(stream, til, &self.fmt)
-    }
-
-    /// Prompts the field, using the given format.
-    ///
-    /// It uses the merged version between the format of the written field and the given format.
-    /// It prompts the field until the value provided by the user is correct, then parses it.
-    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
-    ///
-    /// # Panic
-    ///
-    /// If the default value has an incorrect type, this function will panic.
-    pub fn prompt_with<R, W, T>(
-        &self,
-        stream: &mut MenuStream<R, W>,
-        fmt: &Format<'a>,
-    ) -> MenuResult<T>
-    where
-        R: BufRead,
-        W: Write,
-        T: FromStr,
-    {
-        self.prompt_until_with(stream, keep, fmt)
-    }
-
-    /// Prompts the field.
-    ///
-    /// It prompts the field until the value provided by the user is correct, then parses it.
-    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
-    ///
-    /// # Panic
-    ///
-    /// If the default value has an incorrect type, this function will panic.
-    pub fn prompt<R, W, T>(&self, stream: &mut MenuStream<R, W>) -> MenuResult<T>
-    where
-        R: BufRead,
-        W: Write,
-        T: FromStr,
-    {
-        self.prompt_with(stream, &self.fmt)
-    }
-
-    /// Prompts the field and returns the input value, or the default value of the type
-    /// if the input is incorrect, using the given format.
-    ///
-    /// It uses the merged version between the format of the written field and the given format.
-    ///
-    /// It prompts the value once, and if the user entered a correct input, it returns the value,
-    /// otherwise, it attempts to return the default value (see [`Written::default_value`]
-    /// or [`Written::default_env`]), and if there is no default value, it returns
-    /// the [default](Default) implementation of `T`.
-    ///
-    /// # Panics
-    ///
-    /// If the default value has an incorrect type, this function will panic.
-    pub fn prompt_or_default_with<R, W, T>(
-        &self,
-        stream: &mut MenuStream<R, W>,
-        fmt: &Format<'_>,
-    ) -> T
-    where
-        R: BufRead,
-        W: Write,
-        T: FromStr + Default,
-    {
-        self.optional_value_with(stream, fmt)
-            .map(Option::unwrap_or_default)
-            .unwrap_or_default()
-    }
-
-    /// Prompts the field and returns the input value, or the default value of the type
-    /// if the input is incorrect.
-    ///
-    /// It prompts the value once, and if the user entered a correct input, it returns the value,
-    /// otherwise, it attempts to return the default value (see [`Written::default_value`]
-    /// or [`Written::default_env`]), and if there is no default value, it returns
-    /// the [default](Default) implementation of `T`.
-    ///
-    /// # Panics
-    ///
-    /// If the default value has an incorrect type, this function will panic.
-    pub fn prompt_or_default<R, W, T>(&self, stream: &mut MenuStream<R, W>) -> T
-    where
-        R: BufRead,
-        W: Write,
-        T: FromStr + Default,
-    {
-        self.prompt_or_default_with(stream, &self.fmt)
-    }
-}
-
-/// Used to define a selectable type.
+/// Implemented by the fields containing their own [format](Format).
 ///
-/// It provides the fields, corresponding to a message and the return value.
-/// It is used by the [`Selected`] struct with its `From<&str>` implementation.
-///
-/// The `N` const generic parameter represents the amount of available selectable values.
-///
-/// # Example
-///
-/// ```
-/// # use ezmenulib::field::Selectable;
-/// enum Type {
-///     MIT,
-///     GPL,
-///     BSD,
-/// }
-///
-/// impl Selectable<3> for Type {
-///     fn values() -> [(&'static str, Self); 3] {
-///         [
-///             ("MIT", Self::MIT),
-///             ("GPL", Self::GPL),
-///             ("BSD", Self::BSD),
-///         ]
-///     }
-/// }
-/// ```
-pub trait Selectable<const N: usize>: Sized {
-    /// Provides the fields, corresponding to a message and the return value.
-    fn values() -> [(&'static str, Self); N];
-
-    /// Provides the default fields, by its index, if it is available.
-    fn default() -> Option<usize> {
-        None
-    }
+/// The format of the field is merged with the global format of the container
+/// when prompting it (see [`Values::explain_format`](crate::menu::Values::explain_format)).
+pub trait UsesFormat {
+    /// Returns the format of the field.
+    fn get_format(&self) -> &Format<'_>;
 }
 
-/// Defines the behavior for a selected value provided by the user.
-///
-/// Like the [written](Written) values, it contains its own [format](Format),
-/// and it can be inherited, saving the custom format specifications.
-///
-/// It displays the message with the available fields to select, with the
-/// default field marked as "(default)" if it is provided (see [`Selected::default`] function).
-/// You have to provide a mutable reference to a [`MenuStream`] to retrieve the selected value.
-///
-/// You can use beside it the [`Selectable`] trait to list the available values to select.
-///
-/// The `N` const generic parameter represents the amount of available selectable values.
-///
-/// # Example
-///
-/// For a make-license CLI program for example, you can use it like below:
-///
-/// ```no_run
-/// use ezmenulib::prelude::*;
+/// Defines the amount of details displayed with the fields.
 ///
-/// enum Type {
-///     MIT,
-///     GPL,
-///     BSD,
-/// }
-///
-/// impl Selectable<3> for Type {
-///     fn values() -> [(&'static str, Self); 3] {
-///         use Type::*;
-///         [
-///             ("MIT", MIT),
-///             ("GPL", GPL),
-///             ("BSD", BSD),
-///         ]
-///     }
-/// }
-///
-/// let s: Type = Selected::from("Select the license type")
-///     .select(&mut MenuStream::default())
-///     .unwrap();
-/// ```
-// Clone is implemented on it because it is moved once the user selected the value.
-#[derive(Debug, Clone)]
-pub struct Selected<'a, T, const N: usize> {
-    /// The format used by the selected field value.
-    pub fmt: Format<'a>,
-    msg: &'a str,
-    fields: [(&'a str, T); N],
-    default: Option<usize>,
+/// It is used by the [`Format::verbosity`] specification, so the same fields can serve
+/// both expert users, with terse prompts, and novices, with explanatory prompts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verbosity {
+    /// Only displays the unit of the written fields.
+    ///
+    /// The examples, the default values and the optional hints are hidden.
+    Minimal,
+    /// Displays the details of the fields, according to the other specifications.
+    Normal,
+    /// Displays the details of the fields, then a help line explaining how to answer.
+    Verbose,
 }
 
-impl<'a, T, const N: usize> From<&'a str> for Selected<'a, T, N>
-where
-    T: Selectable<N>,
-{
-    fn from(msg: &'a str) -> Self {
-        Self::inner_new(msg, T::values(), T::default().map(|i| i + 1))
-    }
-}
-
-impl<'a, T, const N: usize> Selected<'a, T, N> {
-    fn inner_new(msg: &'a str, fields: [(&'a str, T); N], default: Option<usize>) -> Self {
-        check_fields(fields.as_ref());
-
-        Self {
-            fmt: Default::default(),
-            msg,
-            fields,
-            default,
-        }
-    }
-
-    /// Returns the Selected wrapper using the given message and
-    /// selectable fields.
-    ///
-    /// # Note
-    ///
-    /// If `T` implements `Selectable`, you may use the `From<&str>` implementation
-    /// for `Selected`, to not write again the available selectable fields.
-    ///
-    /// # Panic
-    ///
-    /// If the fields array is empty, this function will panic. Indeed,
-    /// when prompting the index to the user to select with an empty list, it will generate an
-    /// infinite loop.
-    pub fn new(msg: &'a str, fields: [(&'a str, T); N]) -> Self {
-        Self::inner_new(msg, fields, None)
-    }
-
-    /// Gives a custom formatting for the selected value.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// # use ezmenulib::field::{Selected, Format, Selectable};
-    /// enum Type {
-    ///     MIT,
-    ///     GPL,
-    ///     BSD,
-    /// }
-    ///
-    /// let w = Selected::new("Select the license type", [
-    ///     ("MIT", Type::MIT),
-    ///     ("GPL", Type::GPL),
-    ///     ("BSD", Type::BSD),
-    /// ])
-    /// .format(Format::prefix("==> "));
-    /// ```
-    pub fn format(mut self, fmt: Format<'a>) -> Self {
-        self.fmt = fmt;
-        // Saves the default suffix if asked to break the line,
-        // because it would be ugly to have for instance ": " as suffix.
-        // This is useful if the format is inherited (see [`Values::selected`] function).
-        if !self.fmt.line_brk {
-            self.fmt.suffix = DEFAULT_FMT.suffix;
-        }
-        self
-    }
-
-    /// Defines the default value among the the selectable values, by its index.
-    ///
-    /// # Note
-    ///
-    /// If the index is out of bounds, it will not panic at runtime. Therefore,
-    /// if the user enters an incorrect index, it will not use the default index.
-    pub fn default(mut self, default: usize) -> Self {
-        self.default = Some(default + 1);
-        self
-    }
-
-    /// Prompts the selectable fields once.
-    ///
-    /// In fact, it only displays the suffix, and gets the user input, then returns
-    /// the correct index wrapped in a `Query`.
-    fn prompt_once<R: BufRead, W: Write>(
-        &self,
-        stream: &mut MenuStream<R, W>,
-    ) -> MenuResult<Option<usize>> {
-        select(stream, self.fmt.suffix, N).map(|o| o.or(self.default))
-    }
-
-    /// Prompts the selectable fields and returns the value at the input index,
-    /// or `None` if the index is incorrect.
-    ///
-    /// It prompts the selectable fields once, and if the user entered a correct index,
-    /// it returns `Some(value)` where `value` corresponds to the value mapped to this index,
-    /// otherwise, it attempts to return the value mapped by the default index
-    /// (see `Selected::default`), and if there is no default index, it returns `None`.
-    ///
-    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]).
-    pub fn optional_select<R, W>(self, stream: &mut MenuStream<R, W>) -> MenuResult<Option<T>>
-    where
-        R: BufRead,
-        W: Write,
-    {
-        // Uses the alternate form of selection field display
-        // to display the "(optional)" string slice message.
-        show(&format!("{:#}", self), stream)?;
-
-        Ok(self.prompt_once(stream)?.map(|i| {
-            // SAFETY: the `Selected::prompt_once` guarantees that the index is in bounds.
-            unsafe { self.take(i) }
-        }))
-    }
-
-    /// Gives the value stored at index `i`, consuming `self`.
-    ///
-    /// The index must be in bounds, or this will cause an undefined behavior.
-    ///
-    /// # Safety
-    ///
-    /// The `i` index must be in bounds, meaning `i < N`.
-    /// Otherwise, this function results in an undefined behavior.
-    unsafe fn take(self, i: usize) -> T {
-        self.fields.into_iter().nth(i).unwrap_unchecked().1
-    }
-
-    /// Prompts the selectable values to the user.
-    ///
-    /// It prompts the fields once and the suffix until the index provided, then returns the selected value.
-    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
-    ///
-    /// This function consumes `self` because it returns the ownership of a contained value
-    /// (`T`) defined earlier in the [`Selected::new`] function.
-    pub fn select<R, W>(self, stream: &mut MenuStream<R, W>) -> MenuResult<T>
-    where
-        R: BufRead,
-        W: Write,
-    {
-        show(&self, stream)?;
-        loop {
-            match self.prompt_once(stream)? {
-                // SAFETY: the `Selected::prompt_once` guarantees that the index is in bounds.
-                Some(out) => return Ok(unsafe { self.take(out) }),
-                None => continue,
-            }
-        }
-    }
-
-    /// Prompts the selectable values to the user, and returns the value at the input index,
-    /// or the default index if the input is incorrect.
-    ///
-    /// It prompts the selectable values once, and if the user entered a correct index,
-    /// it returns the value mapped to this index, otherwise, it attempts to return the value
-    /// mapped to the default index (see [`Selected::default`]), and if there is no default value,
-    /// it returns the [default](Default) implementation of `T`.
-    pub fn select_or_default<R, W>(self, stream: &mut MenuStream<R, W>) -> T
-    where
-        R: BufRead,
-        W: Write,
-        T: Default,
-    {
-        self.optional_select(stream)
-            .map(Option::unwrap_or_default)
-            .unwrap_or_default()
-    }
-}
-
-impl<T, const N: usize> Display for Selected<'_, T, N> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}{}", self.fmt.prefix, self.msg)?;
-        if f.alternate() && self.default.is_none()
-            || self.default.is_some() && !self.fmt.show_default
-        {
-            f.write_str(" (optional)")?;
-        }
-        f.write_str("\n")?;
-
-        for (i, (msg, _)) in (1..=N).zip(self.fields.iter()) {
-            write!(
-                f,
-                "{}{i}{}{}{msg}",
-                self.fmt.left_sur, self.fmt.right_sur, self.fmt.chip
-            )?;
-            match self.default {
-                Some(x) if x == i && self.fmt.show_default => f.write_str(" (default)")?,
-                _ => (),
-            }
-            f.write_str("\n")?;
-        }
-
-        Ok(())
-    }
-}
-
-/// A menu field.
-///
-/// The string slice corresponds to the message displayed in the list,
-/// and the kind corresponds to its behavior.
-///
-/// See [`Kind`] for more information.
-pub type Field<'a, R = In, W = Out> = (&'a str, Kind<'a, R, W>);
-
-/// The menu fields.
-///
-/// It simply corresponds to a slice of fields.
-/// It is used for more convenience in the library.
-pub type Fields<'a, R = In, W = Out> = &'a [Field<'a, R, W>];
-
-/// Corresponds to the function mapped to a field.
+/// Defines how the indexes of a list are displayed.
 ///
-/// This function is called right after the user selected the corresponding field.
-///
-/// See [`Kind::Map`] for more information.
-// pub type Binding<R = In, W = Out> = fn(&mut MenuStream<R, W>) -> MenuResult;
-pub type Binding<R, W> = dyn Fn(&mut MenuStream<R, W>) -> MenuResult;
-
-/// Defines the behavior of a menu [field](Field).
-pub enum Kind<'a, R = In, W = Out> {
-    /// Maps a function to call right after the user selects the field.
-    Map(&'a Binding<R, W>),
-    /// Defines the current field as a parent menu of a sub-menu defined by the given fields.
-    Parent(Fields<'a, R, W>),
-    /// Allows the user to go back to the given depth level from the current running prompt.
-    ///
-    /// The depth level of the current running prompt is at `0`, meaning it will stay at
-    /// the current level if the index is at `0` when the user will select the field.
-    Back(usize),
-    /// Closes all the nested menus to the top when the user selects the field.
-    Quit,
+/// It is used by the [`Format::index_style`] specification, so the lists can match
+/// the conventions of an existing program, e.g. `a) b) c)` with the alphabetic style
+/// and `")"` as the right surrounding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexStyle {
+    /// Displays the indexes as numbers, starting from [`Format::start_index`].
+    Numeric,
+    /// Displays the indexes as lowercase letters: `a` to `z`, then `aa`, `ab`, etc.
+    ///
+    /// The user enters `next` or `prev` to change the page of a list,
+    /// because `n` and `p` are indexes.
+    Alphabetic,
+    /// Displays the indexes as lowercase roman numerals: `i`, `ii`, `iii`, `iv`, etc.
+    Roman,
 }
 
-impl<'a, R, W> fmt::Debug for Kind<'a, R, W> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        f.write_str("Field::")?;
+impl IndexStyle {
+    /// Returns the index of the field at the given position of a list, starting from `0`.
+    fn label(self, i: usize, start: usize) -> String {
         match self {
-            Self::Map(_) => f.write_str("Map"),
-            Self::Parent(fields) => f.debug_tuple("Parent").field(fields).finish(),
-            Self::Back(i) => f.debug_tuple("Back").field(i).finish(),
-            Self::Quit => f.write_str("Quit"),
+            Self::Numeric => (i + start).to_string(),
+            Self::Alphabetic => {
+                let (mut n, mut s) = (i + 1, Vec::new());
+                while n > 0 {
+                    n -= 1;
+                    s.push(b'a' + (n % 26) as u8);
+                    n /= 26;
+                }
+                s.iter().rev().map(|c| *c as char).collect()
+            }
+            Self::Roman => {
+                const NUMERALS: [(usize, &str); 13] = [
+                    (1000, "m"),
+                    (900, "cm"),
+                    (500, "d"),
+                    (400, "cd"),
+                    (100, "c"),
+                    (90, "xc"),
+                    (50, "l"),
+                    (40, "xl"),
+                    (10, "x"),
+                    (9, "ix"),
+                    (5, "v"),
+                    (4, "iv"),
+                    (1, "i"),
+                ];
+                let (mut n, mut s) = (i + 1, String::new());
+                for (value, numeral) in NUMERALS {
+                    while n >= value {
+                        s.push_str(numeral);
+                        n -= value;
+                    }
+                }
+                s
+            }
         }
     }
 }
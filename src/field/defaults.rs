@@ -0,0 +1,123 @@
+//! Module defining the providers of default values for the fields.
+//!
+//! A provider maps keys to default values, so that the fields can retrieve their default
+//! value from a configuration, a profile, the environment, etc.
+//! See [`Written::default_from`](crate::field::Written::default_from) and
+//! [`Selected::default_from`](crate::field::Selected::default_from) functions.
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::env;
+use std::hash::{BuildHasher, Hash};
+
+#[cfg(feature = "toml")]
+use crate::{MenuError, MenuResult};
+#[cfg(feature = "toml")]
+use std::{fs, path::Path};
+
+/// Provides the default values of the fields from their key.
+///
+/// The returned value is then parsed by the field like any other default value.
+///
+/// # Example
+///
+/// ```
+/// # use ezmenulib::prelude::*;
+/// # use std::collections::HashMap;
+/// let profile = HashMap::from([("name", "Ahmad"), ("age", "19")]);
+/// let name = Written::from("What is your name?").default_from(&profile, "name");
+/// ```
+pub trait DefaultsProvider {
+    /// Returns the default value mapped to the given key, if any.
+    fn get(&self, key: &str) -> Option<String>;
+}
+
+impl<P: DefaultsProvider + ?Sized> DefaultsProvider for &P {
+    fn get(&self, key: &str) -> Option<String> {
+        (**self).get(key)
+    }
+}
+
+impl<K, V, S> DefaultsProvider for HashMap<K, V, S>
+where
+    K: Borrow<str> + Hash + Eq,
+    V: AsRef<str>,
+    S: BuildHasher,
+{
+    fn get(&self, key: &str) -> Option<String> {
+        HashMap::get(self, key).map(|v| v.as_ref().to_owned())
+    }
+}
+
+/// Provides the default values from the environment variables.
+///
+/// The key of the field is used as the name of the variable, with an optional prefix.
+/// Unlike the [`Written::default_env`](crate::field::Written::default_env) function,
+/// a missing variable is not an error, and the field simply has no default value.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EnvDefaults<'a> {
+    prefix: &'a str,
+}
+
+impl<'a> EnvDefaults<'a> {
+    /// Returns the provider prepending the given prefix to the keys,
+    /// e.g. `APP_` to get the `APP_NAME` variable from the `NAME` key.
+    pub fn prefixed(prefix: &'a str) -> Self {
+        Self { prefix }
+    }
+}
+
+impl DefaultsProvider for EnvDefaults<'_> {
+    fn get(&self, key: &str) -> Option<String> {
+        env::var(format!("{}{key}", self.prefix)).ok()
+    }
+}
+
+/// Provides the default values from a TOML document.
+///
+/// The keys may be dotted to get a value inside a table, e.g. `db.host`.
+#[cfg(feature = "toml")]
+#[cfg_attr(nightly, doc(cfg(feature = "toml")))]
+#[derive(Debug, Clone)]
+pub struct TomlDefaults {
+    table: toml::value::Table,
+}
+
+#[cfg(feature = "toml")]
+impl TomlDefaults {
+    /// Returns the provider from the content of a TOML document.
+    ///
+    /// It returns an error if the document is incorrect.
+    pub fn from_toml(s: &str) -> MenuResult<Self> {
+        s.parse::<toml::Value>()
+            .map_err(MenuError::other)
+            .and_then(|v| match v {
+                toml::Value::Table(table) => Ok(Self { table }),
+                _ => Err("the TOML document must be a table".into()),
+            })
+    }
+
+    /// Returns the provider from the TOML file at the given path.
+    ///
+    /// It returns an error if the file cannot be read, or if its content is incorrect.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> MenuResult<Self> {
+        Self::from_toml(&fs::read_to_string(path)?)
+    }
+}
+
+#[cfg(feature = "toml")]
+impl DefaultsProvider for TomlDefaults {
+    fn get(&self, key: &str) -> Option<String> {
+        let mut keys = key.split('.');
+        let mut value = self.table.get(keys.next()?)?;
+        for key in keys {
+            value = value.as_table()?.get(key)?;
+        }
+
+        match value {
+            toml::Value::String(s) => Some(s.clone()),
+            toml::Value::Table(_) | toml::Value::Array(_) => None,
+            other => Some(other.to_string()),
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! Module defining the fields of the raw menus.
+
+use crate::prelude::*;
+use std::any::Any;
+use std::fmt::{self, Formatter};
+
+/// A menu field.
+///
+/// The string slice corresponds to the message displayed in the list,
+/// and the kind corresponds to its behavior.
+///
+/// See [`Kind`] for more information.
+pub type Field<'a, R = In, W = Out> = (&'a str, Kind<'a, R, W>);
+
+/// The menu fields.
+///
+/// It simply corresponds to a slice of fields.
+/// It is used for more convenience in the library.
+pub type Fields<'a, R = In, W = Out> = &'a [Field<'a, R, W>];
+
+/// Corresponds to the function mapped to a field.
+///
+/// This function is called right after the user selected the corresponding field.
+///
+/// See [`Kind::Map`] for more information.
+// pub type Binding<R = In, W = Out> = fn(&mut MenuStream<R, W>) -> MenuResult;
+pub type Binding<R, W> = dyn Fn(&mut MenuStream<R, W>) -> MenuResult;
+
+/// Corresponds to the function mapped to a field, returning an optional value.
+///
+/// This function is called right after the user selected the corresponding field.
+///
+/// See [`Kind::Value`] for more information.
+pub type ValueBinding<R, W> = dyn Fn(&mut MenuStream<R, W>) -> MenuResult<Option<Box<dyn Any>>>;
+
+/// Corresponds to the function mapped to a field, returning the navigation instruction
+/// of the menu.
+///
+/// This function is called right after the user selected the corresponding field.
+///
+/// See [`Kind::MapNavigate`] for more information.
+pub type NavBinding<R, W> = dyn Fn(&mut MenuStream<R, W>) -> MenuResult<Navigation>;
+
+/// The navigation instruction returned by a function mapped to a field,
+/// defining the next state of the menu.
+///
+/// See [`Kind::MapNavigate`] for more information.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Navigation {
+    /// Stays at the current menu, like after a [`Kind::Map`] field.
+    Stay,
+    /// Goes back to the given depth level, like a [`Kind::Back`] field.
+    Back(usize),
+    /// Closes all the nested menus to the top, like a [`Kind::Quit`] field.
+    Quit,
+    /// Goes back to the parent menu opened by the field with the given message.
+    ///
+    /// The top-level menu is identified by an empty string. If no parent menu
+    /// has the given message, the menu returns an error.
+    Parent(String),
+}
+
+/// A menu field generated at runtime.
+///
+/// Unlike [`Field`], it owns its message, so it can be built from runtime data
+/// (file names, connected devices, etc).
+pub type DynField<'a, R = In, W = Out> = (String, Kind<'a, R, W>);
+
+/// Corresponds to the function generating the fields of a dynamic menu.
+///
+/// This function is called each time the menu is displayed, so the fields
+/// are always up to date.
+///
+/// See [`Kind::Dynamic`] for more information.
+pub type DynFields<'a, R = In, W = Out> =
+    dyn Fn(&mut MenuStream<R, W>) -> Vec<DynField<'a, R, W>> + 'a;
+
+/// Defines the behavior of a menu [field](Field).
+pub enum Kind<'a, R = In, W = Out> {
+    /// Maps a function to call right after the user selects the field.
+    Map(&'a Binding<R, W>),
+    /// Maps a function returning an optional value to call right after the user
+    /// selects the field.
+    ///
+    /// If the function returns a value, all the nested menus are closed to the top,
+    /// and the value is retrieved with the [`RawMenu::run_for`](crate::menu::RawMenu::run_for)
+    /// function. Otherwise, it behaves like the [`Kind::Map`] variant.
+    Value(&'a ValueBinding<R, W>),
+    /// Maps a function returning the navigation instruction of the menu to call right after
+    /// the user selects the field.
+    ///
+    /// It allows the application to drive the flow of the menu, e.g. going back
+    /// to the settings menu after saving them.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// RawMenu::from(&[
+    ///     ("Settings", Kind::Parent(&[
+    ///         ("Network", Kind::Parent(&[
+    ///             ("Save", Kind::MapNavigate(&|_| Ok(Navigation::Parent("Settings".to_owned())))),
+    ///         ])),
+    ///         ("Go back", Kind::Back(1)),
+    ///     ])),
+    ///     ("Quit", Kind::Quit),
+    /// ])
+    /// .run()?;
+    /// # Ok(()) }
+    /// ```
+    MapNavigate(&'a NavBinding<R, W>),
+    /// Defines the current field as a parent menu of a sub-menu defined by the given fields.
+    ///
+    /// The sub-menu inherits the format of its parent menu.
+    Parent(Fields<'a, R, W>),
+    /// Defines the current field as a parent menu of a sub-menu defined by the given fields,
+    /// with the given title instead of the field message.
+    ///
+    /// The [usage](crate::menu::Usage) counters and the hotkeys still identify
+    /// the sub-menu fields by the message of the field.
+    ParentTitled(&'a str, Fields<'a, R, W>),
+    /// Defines the current field as a parent menu of a sub-menu defined by the given fields,
+    /// with its own format.
+    ///
+    /// The format overrides the format of the parent menu for the whole sub-tree,
+    /// and keeps the specifications of the parent menu left to default.
+    ParentWith(Format<'a>, Fields<'a, R, W>),
+    /// Defines the current field as a parent menu of a sub-menu whose fields are
+    /// generated by the given function.
+    ///
+    /// The fields are generated again each time the sub-menu is displayed.
+    /// If the function returns no field, the user goes back to the current menu.
+    Dynamic(&'a DynFields<'a, R, W>),
+    /// Allows the user to go back to the given depth level from the current running prompt.
+    ///
+    /// The depth level of the current running prompt is at `0`, meaning it will stay at
+    /// the current level if the index is at `0` when the user will select the field.
+    Back(usize),
+    /// Closes all the nested menus to the top when the user selects the field.
+    Quit,
+}
+
+impl<R, W> Kind<'_, R, W> {
+    /// Returns `true` if the field displays a submenu when it is selected.
+    pub(crate) fn is_parent(&self) -> bool {
+        matches!(
+            self,
+            Self::Parent(_) | Self::ParentTitled(..) | Self::ParentWith(..) | Self::Dynamic(_)
+        )
+    }
+}
+
+impl<'a, R, W> fmt::Debug for Kind<'a, R, W> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("Field::")?;
+        match self {
+            Self::Map(_) => f.write_str("Map"),
+            Self::Value(_) => f.write_str("Value"),
+            Self::MapNavigate(_) => f.write_str("MapNavigate"),
+            Self::Parent(fields) => f.debug_tuple("Parent").field(fields).finish(),
+            Self::ParentTitled(title, fields) => f
+                .debug_tuple("ParentTitled")
+                .field(title)
+                .field(fields)
+                .finish(),
+            Self::ParentWith(fmt, fields) => f
+                .debug_tuple("ParentWith")
+                .field(fmt)
+                .field(fields)
+                .finish(),
+            Self::Dynamic(_) => f.write_str("Dynamic"),
+            Self::Back(i) => f.debug_tuple("Back").field(i).finish(),
+            Self::Quit => f.write_str("Quit"),
+        }
+    }
+}
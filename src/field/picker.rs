@@ -0,0 +1,230 @@
+//! Module defining the file picker, selecting a file among the entries of a directory.
+
+use crate::prelude::*;
+use crate::utils::{select, truncate, Choice, Pages};
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+
+/// An entry of the directory listed by a [`FilePicker`].
+#[derive(Debug)]
+pub(crate) struct DirEntry {
+    /// The label of the entry, ending with `/` for the directories.
+    pub(crate) label: String,
+    pub(crate) path: PathBuf,
+    pub(crate) is_dir: bool,
+}
+
+/// Defines a field selecting a file among the entries of a directory.
+///
+/// The entries are displayed like the [selectable values](Selected), the directories first,
+/// and the list can be paged (see [`Format::page_size`]). Selecting a directory lists
+/// its entries, and the first entry, `../`, goes back to the parent directory.
+/// Selecting a file returns its absolute path.
+///
+/// # Example
+///
+/// ```no_run
+/// # use ezmenulib::prelude::*;
+/// # fn main() -> MenuResult {
+/// let config = FilePicker::from("Select the configuration file")
+///     .dir("config")
+///     .extensions(&["toml", "json"])
+///     .prompt(&mut MenuStream::default())?;
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct FilePicker<'a> {
+    msg: &'a str,
+    /// The format of the file picker.
+    pub fmt: Format<'a>,
+    dir: PathBuf,
+    extensions: &'a [&'a str],
+    hidden: bool,
+}
+
+impl<'a> From<&'a str> for FilePicker<'a> {
+    fn from(msg: &'a str) -> Self {
+        Self {
+            msg,
+            fmt: Format::default(),
+            dir: PathBuf::from("."),
+            extensions: &[],
+            hidden: false,
+        }
+    }
+}
+
+impl Display for FilePicker<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.msg)
+    }
+}
+
+impl<'a> FilePicker<'a> {
+    /// Defines the format of the file picker.
+    pub fn format(mut self, fmt: Format<'a>) -> Self {
+        self.fmt = fmt;
+        self
+    }
+
+    /// Defines the directory listed first (the current directory by default).
+    pub fn dir<P: Into<PathBuf>>(mut self, dir: P) -> Self {
+        self.dir = dir.into();
+        self
+    }
+
+    /// Defines the extensions of the files displayed, without the leading dot.
+    ///
+    /// If no extension is given, all the files are displayed. The directories
+    /// are always displayed.
+    pub fn extensions(mut self, extensions: &'a [&'a str]) -> Self {
+        self.extensions = extensions;
+        self
+    }
+
+    /// Defines if the hidden entries, whose name starts with a dot, are displayed
+    /// (`false` by default).
+    pub fn hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    /// Returns the absolute path of the directory listed first.
+    pub(crate) fn start_dir(&self) -> io::Result<PathBuf> {
+        fs::canonicalize(&self.dir)
+    }
+
+    /// Returns the entries of the given directory displayed by the file picker,
+    /// with the parent directory first.
+    pub(crate) fn entries(&self, dir: &Path) -> io::Result<Vec<DirEntry>> {
+        let mut entries = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let path = entry.path();
+            let is_dir = path.is_dir();
+
+            let ext = path.extension().unwrap_or_default();
+            let matches_ext =
+                self.extensions.is_empty() || self.extensions.iter().any(|e| ext == *e);
+            if (self.hidden || !name.starts_with('.')) && (is_dir || matches_ext) {
+                entries.push(DirEntry {
+                    label: if is_dir { format!("{}/", name) } else { name },
+                    path,
+                    is_dir,
+                });
+            }
+        }
+        entries.sort_by(|a, b| b.is_dir.cmp(&a.is_dir).then_with(|| a.label.cmp(&b.label)));
+
+        if let Some(parent) = dir.parent() {
+            entries.insert(
+                0,
+                DirEntry {
+                    label: "../".to_owned(),
+                    path: parent.to_owned(),
+                    is_dir: true,
+                },
+            );
+        }
+        Ok(entries)
+    }
+
+    /// Displays the message with the current directory and the entries of the current page.
+    fn fmt_page<S: fmt::Write>(
+        &self,
+        s: &mut S,
+        fmt: &Format<'_>,
+        dir: &Path,
+        entries: &[DirEntry],
+        pages: &Pages,
+    ) -> fmt::Result {
+        writeln!(
+            s,
+            "{}{} ({})",
+            fmt.style.prefix(fmt.prefix),
+            self.msg,
+            dir.display()
+        )?;
+
+        for (i, entry) in pages.range().zip(&entries[pages.range()]) {
+            writeln!(
+                s,
+                "{}{}{}",
+                fmt.index(i),
+                fmt.chip,
+                truncate(&entry.label, fmt.max_label_width),
+            )?;
+        }
+
+        pages.fmt_controls(s, fmt)
+    }
+
+    /// Prompts the entries of the directories until the user selects a file,
+    /// using the given format.
+    ///
+    /// It uses the merged version between the format of the file picker and the given format.
+    /// It returns the absolute path of the selected file, or an error if the directory
+    /// listed first cannot be read. If a selected directory cannot be read, the error is displayed
+    /// and the current directory stays the same.
+    pub fn prompt_with<R: BufRead, W: Write>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        fmt: &Format<'a>,
+    ) -> MenuResult<PathBuf> {
+        let fmt = stream.output_format(&self.fmt.merged(fmt));
+        let mut dir = self.start_dir()?;
+        let mut entries = self.entries(&dir)?;
+
+        loop {
+            let mut pages = Pages::new(fmt.page_size, entries.len());
+            self.fmt_page(stream, &fmt, &dir, &entries, &pages)?;
+
+            // Loops while the current directory stays the same.
+            loop {
+                match select(
+                    stream,
+                    &fmt,
+                    fmt.suffix,
+                    entries.len(),
+                    pages.is_paged(),
+                    &[],
+                )? {
+                    Choice::Index(i) if entries[i].is_dir => match self.entries(&entries[i].path) {
+                        Ok(sub) => {
+                            dir = entries[i].path.clone();
+                            entries = sub;
+                            break;
+                        }
+                        Err(e) => writeln!(
+                            stream,
+                            "{}",
+                            fmt.style.error(format!("{}: {}", entries[i].label, e))
+                        )?,
+                    },
+                    Choice::Index(i) => return Ok(entries[i].path.clone()),
+                    Choice::Next if pages.next() => {
+                        self.fmt_page(stream, &fmt, &dir, &entries, &pages)?
+                    }
+                    Choice::Prev if pages.prev() => {
+                        self.fmt_page(stream, &fmt, &dir, &entries, &pages)?
+                    }
+                    _ => (),
+                }
+            }
+        }
+    }
+
+    /// Prompts the entries of the directories until the user selects a file.
+    ///
+    /// It returns the absolute path of the selected file, or an error if the directory
+    /// listed first cannot be read.
+    pub fn prompt<R: BufRead, W: Write>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+    ) -> MenuResult<PathBuf> {
+        self.prompt_with(stream, &self.fmt)
+    }
+}
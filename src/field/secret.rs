@@ -0,0 +1,92 @@
+//! Module defining the secrets, loaded from the credential store of the system.
+
+use crate::customs::MenuBool;
+use crate::prelude::*;
+use keyring::{Entry, Error as KeyringError};
+use std::io::{BufRead, Write};
+
+/// A secret value, like a password or an API token, loaded from the credential store
+/// of the system.
+///
+/// The secret is identified by the name of the service and the name of the user.
+/// It is loaded from the Keychain on macOS, the Credential Manager on Windows,
+/// or the Secret Service on Linux. The written field is only prompted if the secret
+/// is absent from the store. The user may then be offered to store the entered secret,
+/// so it is not asked again on the next runs.
+///
+/// # Example
+///
+/// ```no_run
+/// # use ezmenulib::prelude::*;
+/// # fn main() -> MenuResult {
+/// let token = Secret::new(Written::from("API token"), "my-app", "deploy")
+///     .offer_store(true)
+///     .prompt(&mut MenuStream::default())?;
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct Secret<'a> {
+    written: Written<'a>,
+    service: &'a str,
+    user: &'a str,
+    offer_store: bool,
+}
+
+impl<'a> Secret<'a> {
+    /// Returns the secret identified by the given service and user names,
+    /// prompted with the given written field if it is absent from the store.
+    pub fn new(written: Written<'a>, service: &'a str, user: &'a str) -> Self {
+        Self {
+            written,
+            service,
+            user,
+            offer_store: false,
+        }
+    }
+
+    /// Defines if the user is asked to store the entered secret in the credential store
+    /// of the system (`false` by default).
+    pub fn offer_store(mut self, offer: bool) -> Self {
+        self.offer_store = offer;
+        self
+    }
+
+    /// Loads the secret from the credential store, or prompts the written field
+    /// with the given format if it is absent.
+    ///
+    /// It uses the merged version between the format of the written field and the given format.
+    /// It returns an error if the credential store cannot be accessed.
+    pub fn prompt_with<R: BufRead, W: Write>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        fmt: &Format<'a>,
+    ) -> MenuResult<String> {
+        let entry = Entry::new(self.service, self.user).map_err(MenuError::other)?;
+        match entry.get_password() {
+            Ok(secret) => return Ok(secret),
+            Err(KeyringError::NoEntry) => (),
+            Err(e) => return Err(MenuError::other(e)),
+        }
+
+        let secret: String = self.written.prompt_with(stream, fmt)?;
+        if self.offer_store {
+            let store: MenuBool = Written::from("Save it to the credential store?")
+                .default_value("no")
+                .prompt_with(stream, fmt)?;
+            if *store {
+                entry.set_password(&secret).map_err(MenuError::other)?;
+            }
+        }
+        Ok(secret)
+    }
+
+    /// Loads the secret from the credential store, or prompts the written field if it is absent.
+    ///
+    /// It returns an error if the credential store cannot be accessed.
+    pub fn prompt<R: BufRead, W: Write>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+    ) -> MenuResult<String> {
+        self.prompt_with(stream, &self.written.fmt)
+    }
+}
@@ -0,0 +1,755 @@
+//! Module defining the selectable fields, retrieving a value among a list.
+
+use crate::i18n::{tr, tr_label, tr_msg};
+use crate::prelude::*;
+use crate::utils::*;
+use crate::DEFAULT_FMT;
+use std::borrow::Cow;
+use std::fmt::{self, Display, Formatter};
+use std::io::{BufRead, Write};
+use std::process::Command;
+#[cfg(feature = "crossterm")]
+use std::time::Duration;
+
+/// Used to define a selectable type.
+///
+/// It provides the fields, corresponding to a message and the return value.
+/// It is used by the [`Selected`] struct with its `From<&str>` implementation.
+///
+/// The `N` const generic parameter represents the amount of available selectable values.
+///
+/// # Example
+///
+/// ```
+/// # use ezmenulib::field::Selectable;
+/// enum Type {
+///     MIT,
+///     GPL,
+///     BSD,
+/// }
+///
+/// impl Selectable<3> for Type {
+///     fn values() -> [(&'static str, Self); 3] {
+///         [
+///             ("MIT", Self::MIT),
+///             ("GPL", Self::GPL),
+///             ("BSD", Self::BSD),
+///         ]
+///     }
+/// }
+/// ```
+pub trait Selectable<const N: usize>: Sized {
+    /// Provides the fields, corresponding to a message and the return value.
+    fn values() -> [(&'static str, Self); N];
+
+    /// Provides the default fields, by its index, if it is available.
+    fn default() -> Option<usize> {
+        None
+    }
+}
+
+/// Defines the behavior for a selected value provided by the user.
+///
+/// Like the [written](Written) values, it contains its own [format](Format),
+/// and it can be inherited, saving the custom format specifications.
+///
+/// It displays the message with the available fields to select, with the
+/// default field marked as "(default)" if it is provided (see [`Selected::default`] function).
+/// You have to provide a mutable reference to a [`MenuStream`] to retrieve the selected value.
+///
+/// You can use beside it the [`Selectable`] trait to list the available values to select.
+///
+/// The `N` const generic parameter represents the amount of available selectable values
+/// when they are given as an array. It is `0` for the values collected at runtime
+/// (see [`Selected::from_iter`] and [`Selected::from_fields`]).
+///
+/// # Example
+///
+/// For a make-license CLI program for example, you can use it like below:
+///
+/// ```no_run
+/// use ezmenulib::prelude::*;
+///
+/// enum Type {
+///     MIT,
+///     GPL,
+///     BSD,
+/// }
+///
+/// impl Selectable<3> for Type {
+///     fn values() -> [(&'static str, Self); 3] {
+///         use Type::*;
+///         [
+///             ("MIT", MIT),
+///             ("GPL", GPL),
+///             ("BSD", BSD),
+///         ]
+///     }
+/// }
+///
+/// let s: Type = Selected::from("Select the license type")
+///     .select(&mut MenuStream::default())
+///     .unwrap();
+/// ```
+// Clone is implemented on it because it is moved once the user selected the value.
+#[derive(Debug, Clone)]
+pub struct Selected<'a, T, const N: usize = 0> {
+    /// The format used by the selected field value.
+    pub fmt: Format<'a>,
+    pub(crate) msg: &'a str,
+    fields: Vec<(Cow<'a, str>, T)>,
+    default: Option<usize>,
+    max_attempts: Option<usize>,
+    i18n: Option<&'a str>,
+    #[cfg(feature = "crossterm")]
+    timeout: Option<Duration>,
+}
+
+impl<'a, T, const N: usize> From<&'a str> for Selected<'a, T, N>
+where
+    T: Selectable<N>,
+{
+    fn from(msg: &'a str) -> Self {
+        Self::inner_new(msg, T::values(), T::default().map(|i| i + 1))
+    }
+}
+
+impl<'a, T> Selected<'a, T> {
+    /// Returns the Selected wrapper using the given message and the values of the iterator,
+    /// whose labels are generated from their [`Display`] implementation.
+    ///
+    /// Unlike the [`Selected::new`] function, the amount of values is only known at runtime,
+    /// e.g. for values loaded from a database or a file.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ezmenulib::prelude::*;
+    /// let branches = vec!["main".to_owned(), "dev".to_owned()];
+    /// let sel = Selected::from_iter("Select a branch", branches);
+    /// assert_eq!(sel.to_string(), "--> Select a branch\n[1] - main\n[2] - dev\n");
+    /// ```
+    ///
+    /// # Panic
+    ///
+    /// If the iterator is empty, this function will panic (see [`Selected::new`]).
+    pub fn from_iter<I>(msg: &'a str, values: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Display,
+    {
+        let fields = values
+            .into_iter()
+            .map(|value| (Cow::Owned(value.to_string()), value))
+            .collect();
+        Self::inner_from(msg, fields, None)
+    }
+
+    /// Returns the Selected wrapper using the given message and the selectable fields
+    /// of the iterator, corresponding to a label and the return value.
+    ///
+    /// Unlike the [`Selected::from_vec`] function, the amount of fields is not checked
+    /// against a const generic parameter, so the fields can be built at runtime.
+    /// The labels may be borrowed or owned strings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ezmenulib::prelude::*;
+    /// let users = [(1, "Alice"), (2, "Bob")];
+    /// let sel = Selected::from_fields(
+    ///     "Select a user",
+    ///     users.iter().map(|(id, name)| (format!("{} (#{})", name, id), *id)),
+    /// );
+    /// assert_eq!(sel.to_string(), "--> Select a user\n[1] - Alice (#1)\n[2] - Bob (#2)\n");
+    /// ```
+    ///
+    /// # Panic
+    ///
+    /// If the iterator is empty, this function will panic (see [`Selected::new`]).
+    pub fn from_fields<I, L>(msg: &'a str, fields: I) -> Self
+    where
+        I: IntoIterator<Item = (L, T)>,
+        L: Into<Cow<'a, str>>,
+    {
+        let fields = fields
+            .into_iter()
+            .map(|(label, value)| (label.into(), value))
+            .collect();
+        Self::inner_from(msg, fields, None)
+    }
+}
+
+impl<'a> Selected<'a, String> {
+    /// Returns the Selected wrapper using the given message and the lines read from the reader
+    /// as the selectable values.
+    ///
+    /// The lines are trimmed, and the empty or duplicated lines are skipped. It returns an error
+    /// if the reader fails, or if there is no line to select.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let hosts = "web-1\n  web-2\n\nweb-1\n".as_bytes();
+    /// let sel = Selected::from_lines("Select a host", hosts)?;
+    /// assert_eq!(sel.to_string(), "--> Select a host\n[1] - web-1\n[2] - web-2\n");
+    /// # Ok(()) }
+    /// ```
+    pub fn from_lines<B: BufRead>(msg: &'a str, reader: B) -> MenuResult<Self> {
+        let mut values: Vec<String> = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            let line = line.trim();
+            if !line.is_empty() && !values.iter().any(|v| v == line) {
+                values.push(line.to_owned());
+            }
+        }
+
+        if values.is_empty() {
+            return Err("no line to select".into());
+        }
+        Ok(Self::from_iter(msg, values))
+    }
+
+    /// Returns the Selected wrapper using the given message and the lines printed out
+    /// by the command as the selectable values.
+    ///
+    /// The lines are handled like with the [`Selected::from_lines`] function. It returns
+    /// an error if the command cannot be run, if it does not exit successfully, or if
+    /// it prints out no line to select.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # use std::process::Command;
+    /// # fn main() -> MenuResult {
+    /// let branch = Selected::from_command(
+    ///     "Select a branch",
+    ///     Command::new("git").args(["branch", "--format=%(refname:short)"]),
+    /// )?
+    /// .select(&mut MenuStream::default())?;
+    /// # Ok(()) }
+    /// ```
+    pub fn from_command(msg: &'a str, cmd: &mut Command) -> MenuResult<Self> {
+        let output = cmd.output()?;
+        if !output.status.success() {
+            return Err(format!("the command failed with {}", output.status).into());
+        }
+        Self::from_lines(msg, output.stdout.as_slice())
+    }
+}
+
+impl<'a, T, const N: usize> Selected<'a, T, N> {
+    fn inner_new(msg: &'a str, fields: [(&'a str, T); N], default: Option<usize>) -> Self {
+        Self::inner_from(
+            msg,
+            fields
+                .into_iter()
+                .map(|(msg, value)| (Cow::Borrowed(msg), value))
+                .collect(),
+            default,
+        )
+    }
+
+    fn inner_from(msg: &'a str, fields: Vec<(Cow<'a, str>, T)>, default: Option<usize>) -> Self {
+        check_fields(&fields);
+
+        Self {
+            fmt: Default::default(),
+            msg,
+            fields,
+            default,
+            max_attempts: None,
+            i18n: None,
+            #[cfg(feature = "crossterm")]
+            timeout: None,
+        }
+    }
+
+    /// Returns the Selected wrapper using the given message and
+    /// selectable fields.
+    ///
+    /// # Note
+    ///
+    /// If `T` implements `Selectable`, you may use the `From<&str>` implementation
+    /// for `Selected`, to not write again the available selectable fields.
+    ///
+    /// # Panic
+    ///
+    /// If the fields array is empty, this function will panic. Indeed,
+    /// when prompting the index to the user to select with an empty list, it will generate an
+    /// infinite loop.
+    pub fn new(msg: &'a str, fields: [(&'a str, T); N]) -> Self {
+        Self::inner_new(msg, fields, None)
+    }
+
+    /// Returns the Selected wrapper using the given message and the selectable fields
+    /// of the vector.
+    ///
+    /// It is useful when the fields are collected from an iterator. The amount of fields
+    /// is checked at runtime, and it returns an error if it is not `N`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let fields: Vec<_> = ["red", "green", "blue"].into_iter().map(|c| (c, c.len())).collect();
+    /// let sel = Selected::<_, 3>::from_vec("Pick a color", fields)?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Panic
+    ///
+    /// If the vector is empty, this function will panic (see [`Selected::new`]).
+    pub fn from_vec(msg: &'a str, fields: Vec<(&'a str, T)>) -> MenuResult<Self> {
+        let len = fields.len();
+        fields
+            .try_into()
+            .map(|fields| Self::new(msg, fields))
+            .map_err(|_| format!("expected {} selectable fields, got {}", N, len).into())
+    }
+
+    /// Returns the Selected wrapper using the given message and the selectable fields
+    /// of the slice, by cloning them.
+    ///
+    /// The amount of fields is checked at runtime, and it returns an error if it is not `N`
+    /// (see [`Selected::from_vec`]).
+    ///
+    /// # Panic
+    ///
+    /// If the slice is empty, this function will panic (see [`Selected::new`]).
+    pub fn from_slice(msg: &'a str, fields: &[(&'a str, T)]) -> MenuResult<Self>
+    where
+        T: Clone,
+    {
+        Self::from_vec(msg, fields.to_vec())
+    }
+
+    /// Gives a custom formatting for the selected value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ezmenulib::field::{Selected, Format, Selectable};
+    /// enum Type {
+    ///     MIT,
+    ///     GPL,
+    ///     BSD,
+    /// }
+    ///
+    /// let w = Selected::new("Select the license type", [
+    ///     ("MIT", Type::MIT),
+    ///     ("GPL", Type::GPL),
+    ///     ("BSD", Type::BSD),
+    /// ])
+    /// .format(Format::prefix("==> "));
+    /// ```
+    pub fn format(mut self, fmt: Format<'a>) -> Self {
+        self.fmt = fmt;
+        self
+    }
+
+    /// Defines the default value among the the selectable values, by its index.
+    ///
+    /// # Note
+    ///
+    /// If the index is out of bounds, it will not panic at runtime. Therefore,
+    /// if the user enters an incorrect index, it will not use the default index.
+    pub fn default(mut self, default: usize) -> Self {
+        self.default = Some(default + 1);
+        self
+    }
+
+    /// Defines the maximum amount of incorrect inputs before giving up (`None` by default).
+    ///
+    /// When the limit is reached, selecting a value returns a [`MenuError::TooManyAttempts`]
+    /// error, instead of prompting the fields again forever.
+    pub fn max_attempts(mut self, max: usize) -> Self {
+        self.max_attempts = Some(max);
+        self
+    }
+
+    /// Defines the time the user has to press a key before the default value is selected.
+    ///
+    /// If there is no default value, selecting a value returns a [`MenuError::Timeout`] error.
+    /// The timeout stops once the user pressed a key.
+    ///
+    /// It only applies if the user selects the values with the [arrow keys](MenuStream::arrows),
+    /// as the blocking reads of the [`BufRead`] reader of the stream cannot be interrupted.
+    #[cfg(feature = "crossterm")]
+    #[cfg_attr(nightly, doc(cfg(feature = "crossterm")))]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Defines the key of the message, translated by the installed [localizer](crate::i18n).
+    ///
+    /// See [`Written::i18n`] for more information.
+    pub fn i18n(mut self, key: &'a str) -> Self {
+        self.i18n = Some(key);
+        self
+    }
+
+    /// Defines the default value among the selectable values, retrieved from the provider
+    /// with the given key.
+    ///
+    /// The provided value corresponds to the message of the default selectable field.
+    /// If the provider has no value for this key, or if no field matches it,
+    /// the default index remains unchanged.
+    pub fn default_from<P: DefaultsProvider + ?Sized>(mut self, provider: &P, key: &str) -> Self {
+        if let Some(i) = provider
+            .get(key)
+            .and_then(|msg| self.fields.iter().position(|(field, _)| *field == msg))
+        {
+            self.default = Some(i + 1);
+        }
+        self
+    }
+
+    /// Prompts the selectable fields once.
+    ///
+    /// In fact, it only displays the suffix, and gets the user input, then returns
+    /// the correct index wrapped in an `Option`. If the user moves to another page
+    /// of the fields, the page is displayed and the suffix is prompted again.
+    fn prompt_once<R: BufRead, W: Write>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        pages: &mut Pages,
+        opt: bool,
+    ) -> MenuResult<Option<usize>> {
+        let fmt = stream.output_format(&self.fmt);
+        let inline = self.is_inline(stream);
+        // Saves the default suffix if the line is not broken but the cursor cannot be moved,
+        // because it would be ugly to have for instance ": " as suffix.
+        let suffix = match (inline, fmt.line_brk) {
+            (true, _) => "",
+            (false, true) => fmt.suffix,
+            (false, false) => DEFAULT_FMT.suffix,
+        };
+
+        loop {
+            let below = match inline {
+                true => self.fmt_inline(stream, &fmt, pages, opt)?,
+                false => 0,
+            };
+            let choice = select(
+                stream,
+                &fmt,
+                suffix,
+                self.fields.len(),
+                pages.is_paged(),
+                &[],
+            )?;
+            if below > 0 {
+                // Moves the cursor below the values, before displaying anything else.
+                write!(stream, "\x1b[{}E", below)?;
+            }
+
+            match choice {
+                Choice::Index(i) => return Ok(Some(i)),
+                // The inline page is displayed again at the beginning of the loop.
+                Choice::Next if inline && pages.next() => (),
+                Choice::Prev if inline && pages.prev() => (),
+                Choice::Next if pages.next() => self.fmt_page(stream, &fmt, pages, opt)?,
+                Choice::Prev if pages.prev() => self.fmt_page(stream, &fmt, pages, opt)?,
+                _ => return Ok(self.default_index()),
+            }
+        }
+    }
+
+    /// Returns true if the suffix is displayed on the line of the message,
+    /// with the values listed below.
+    ///
+    /// It is the case if the format does not break the line and the stream is colored
+    /// with the `"color"` feature enabled, so the cursor can be moved back to the line
+    /// of the message.
+    fn is_inline<R, W>(&self, stream: &MenuStream<R, W>) -> bool {
+        cfg!(feature = "color") && !self.fmt.line_brk && stream.is_colored()
+    }
+
+    /// Displays the message followed by the suffix, with the selectable fields of the current
+    /// page below, then moves the cursor back right after the suffix.
+    ///
+    /// It returns the amount of lines displayed below the message.
+    fn fmt_inline<R, W: Write>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        fmt: &Format<'_>,
+        pages: &Pages,
+        opt: bool,
+    ) -> MenuResult<usize> {
+        let mut page = String::new();
+        self.fmt_page(&mut page, fmt, pages, opt)?;
+        // The message always ends with a line break.
+        let (msg, values) = page.split_once('\n').unwrap_or((&page, ""));
+        let below = values.lines().count();
+
+        // The width of the message is computed without the style.
+        let mut plain = String::new();
+        let plain_fmt = Format {
+            style: Style::PLAIN,
+            ..fmt.clone()
+        };
+        self.fmt_msg(&mut plain, &plain_fmt, opt)?;
+        let width = plain.trim_end_matches('\n').chars().count() + fmt.suffix.chars().count();
+
+        write!(stream, "{}{}\n{}", msg, fmt.suffix, values)?;
+        write!(stream, "\x1b[{}F\x1b[{}G", below, width + 1)?;
+        stream.flush()?;
+        Ok(below)
+    }
+
+    /// Returns the amount of selectable values.
+    pub(super) fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Returns the default index starting from 0, if it is in bounds.
+    pub(super) fn default_index(&self) -> Option<usize> {
+        // The default index is displayed starting from 1.
+        self.default
+            .map(|i| i - 1)
+            .filter(|i| *i < self.fields.len())
+    }
+
+    /// Displays the message of the field, marked as optional if `opt` is true
+    /// and there is no default value.
+    fn fmt_msg<S: fmt::Write>(&self, s: &mut S, fmt: &Format<'_>, opt: bool) -> fmt::Result {
+        let msg = tr_msg(self.i18n, self.msg);
+        write!(s, "{}{}", fmt.style.prefix(fmt.prefix), msg)?;
+        let hints = fmt.verbosity != Verbosity::Minimal && fmt.show_optional;
+        if hints && (opt && self.default.is_none() || self.default.is_some() && !fmt.show_default) {
+            let label = tr_label(
+                "ezmenulib.optional",
+                fmt.optional_label,
+                DEFAULT_FMT.optional_label,
+            );
+            write!(s, " ({})", label)?;
+        }
+        s.write_char('\n')
+    }
+
+    /// Lets the user select a field with the arrow keys, and returns its index,
+    /// or the default index if the user pressed `Esc` or if the timeout elapsed.
+    ///
+    /// See [`MenuStream::arrows`] for more information.
+    #[cfg(feature = "crossterm")]
+    fn prompt_arrows<R, W: Write>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        fmt: &Format<'_>,
+        opt: bool,
+    ) -> MenuResult<Option<usize>> {
+        self.fmt_msg(stream, fmt, opt)?;
+        if fmt.verbosity == Verbosity::Verbose {
+            let help = tr(
+                "ezmenulib.help-arrows",
+                "Select a value with the arrow keys, then press Enter.",
+                &[],
+            );
+            writeln!(stream, "{}", help)?;
+        }
+
+        let labels: Vec<_> = self.fields.iter().map(|(msg, _)| msg.as_ref()).collect();
+        let start = self.default_index().unwrap_or(0);
+        let announce = stream.is_announcing();
+        let mut wait = Wait::timeout(self.timeout);
+        Ok(
+            match select_arrows(stream, fmt, &labels, &[], start, &mut wait, announce)? {
+                Choice::Index(i) => Some(i),
+                Choice::Timeout => Some(self.default_index().ok_or(MenuError::Timeout)?),
+                _ => self.default_index(),
+            },
+        )
+    }
+
+    /// Displays the message with the selectable fields of the current page.
+    ///
+    /// It uses the given format instead of the format of the field, because the latter
+    /// may be [adjusted](MenuStream::output_format) to the stream.
+    /// If `opt` is true, the message is marked as optional if there is no default value.
+    pub(super) fn fmt_page<S: fmt::Write>(
+        &self,
+        s: &mut S,
+        fmt: &Format<'_>,
+        pages: &Pages,
+        opt: bool,
+    ) -> fmt::Result {
+        self.fmt_msg(s, fmt, opt)?;
+        if fmt.verbosity == Verbosity::Verbose {
+            let help = tr("ezmenulib.help-index", "Enter the index of a value.", &[]);
+            writeln!(s, "{}", help)?;
+        }
+
+        let hints = fmt.verbosity != Verbosity::Minimal;
+
+        for (i, (msg, _)) in pages.range().zip(&self.fields[pages.range()]) {
+            write!(
+                s,
+                "{}{}{}",
+                fmt.index(i),
+                fmt.chip,
+                truncate(msg, fmt.max_label_width),
+            )?;
+            // The default index is displayed starting from 1.
+            match self.default {
+                Some(x) if x == i + 1 && fmt.show_default && hints => {
+                    let label = tr_label(
+                        "ezmenulib.default",
+                        fmt.default_label,
+                        DEFAULT_FMT.default_label,
+                    );
+                    write!(s, " {}", fmt.style.default_value(format!("({})", label)))?
+                }
+                _ => (),
+            }
+            s.write_str("\n")?;
+        }
+
+        pages.fmt_controls(s, fmt)
+    }
+
+    /// Prompts the selectable fields and returns the value at the input index,
+    /// or `None` if the index is incorrect.
+    ///
+    /// It prompts the selectable fields once, and if the user entered a correct index,
+    /// it returns `Some(value)` where `value` corresponds to the value mapped to this index,
+    /// otherwise, it attempts to return the value mapped by the default index
+    /// (see `Selected::default`), and if there is no default index, it returns `None`.
+    ///
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]).
+    pub fn optional_select<R, W>(self, stream: &mut MenuStream<R, W>) -> MenuResult<Option<T>>
+    where
+        R: BufRead,
+        W: Write,
+    {
+        log_event!(debug, "prompting the selectable field `{}`", self.msg);
+        let mut pages = Pages::new(self.fmt.page_size, self.fields.len());
+        // Displays the "(optional)" string slice message.
+        let fmt = stream.output_format(&self.fmt);
+
+        #[cfg(feature = "crossterm")]
+        if stream.is_arrows() {
+            return Ok(self.prompt_arrows(stream, &fmt, true)?.map(|i| {
+                // SAFETY: the `Selected::prompt_arrows` guarantees that the index is in bounds.
+                unsafe { self.take(i) }
+            }));
+        }
+
+        if !self.is_inline(stream) {
+            self.fmt_page(stream, &fmt, &pages, true)?;
+        }
+
+        Ok(self.prompt_once(stream, &mut pages, true)?.map(|i| {
+            // SAFETY: the `Selected::prompt_once` guarantees that the index is in bounds.
+            unsafe { self.take(i) }
+        }))
+    }
+
+    /// Gives the value stored at index `i`, consuming `self`.
+    ///
+    /// The index must be in bounds, or this will cause an undefined behavior.
+    ///
+    /// # Safety
+    ///
+    /// The `i` index must be in bounds, meaning it is lower than the amount of fields.
+    /// Otherwise, this function results in an undefined behavior.
+    pub(super) unsafe fn take(self, i: usize) -> T {
+        log_event!(info, "selected `{}` for `{}`", self.fields[i].0, self.msg);
+        self.fields.into_iter().nth(i).unwrap_unchecked().1
+    }
+
+    /// Gives the value whose message is the given answer, consuming `self`.
+    pub(crate) fn take_answer(self, answer: &str) -> Option<T> {
+        self.fields
+            .into_iter()
+            .find(|(msg, _)| msg == answer)
+            .map(|(_, value)| value)
+    }
+
+    /// Prompts the selectable values to the user.
+    ///
+    /// It prompts the fields once and the suffix until the index provided, then returns the selected value.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// This function consumes `self` because it returns the ownership of a contained value
+    /// (`T`) defined earlier in the [`Selected::new`] function.
+    pub fn select<R, W>(self, stream: &mut MenuStream<R, W>) -> MenuResult<T>
+    where
+        R: BufRead,
+        W: Write,
+    {
+        log_event!(debug, "prompting the selectable field `{}`", self.msg);
+        let mut pages = Pages::new(self.fmt.page_size, self.fields.len());
+        let fmt = stream.output_format(&self.fmt);
+
+        let mut attempts = 0;
+
+        #[cfg(feature = "crossterm")]
+        if stream.is_arrows() {
+            loop {
+                if let Some(i) = self.prompt_arrows(stream, &fmt, false)? {
+                    // SAFETY: the `Selected::prompt_arrows` guarantees that the index is in bounds.
+                    return Ok(unsafe { self.take(i) });
+                }
+                attempt(self.max_attempts, None, &mut attempts)?;
+            }
+        }
+
+        if !self.is_inline(stream) {
+            self.fmt_page(stream, &fmt, &pages, false)?;
+        }
+
+        loop {
+            match self.prompt_once(stream, &mut pages, false)? {
+                // SAFETY: the `Selected::prompt_once` guarantees that the index is in bounds.
+                Some(out) => return Ok(unsafe { self.take(out) }),
+                None => attempt(self.max_attempts, None, &mut attempts)?,
+            }
+        }
+    }
+
+    /// Prompts the selectable values to the user, and returns the value at the input index,
+    /// or the default index if the input is incorrect.
+    ///
+    /// It prompts the selectable values once, and if the user entered a correct index,
+    /// it returns the value mapped to this index, otherwise, it attempts to return the value
+    /// mapped to the default index (see [`Selected::default`]), and if there is no default value,
+    /// it returns the [default](Default) implementation of `T`.
+    pub fn select_or_default<R, W>(self, stream: &mut MenuStream<R, W>) -> T
+    where
+        R: BufRead,
+        W: Write,
+        T: Default,
+    {
+        self.optional_select(stream)
+            .map(Option::unwrap_or_default)
+            .unwrap_or_default()
+    }
+}
+
+impl<T, const N: usize> Display for Selected<'_, T, N> {
+    /// Displays the message with the selectable fields of the first page.
+    ///
+    /// The alternate form marks the message as optional if there is no default value.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let opt = f.alternate();
+        self.fmt_page(
+            f,
+            &self.fmt,
+            &Pages::new(self.fmt.page_size, self.fields.len()),
+            opt,
+        )
+    }
+}
+
+impl<T, const N: usize> UsesFormat for Selected<'_, T, N> {
+    fn get_format(&self) -> &Format<'_> {
+        &self.fmt
+    }
+}
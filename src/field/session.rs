@@ -0,0 +1,174 @@
+//! Module defining the prompt sessions, used to retrieve values without blocking reads.
+
+use crate::prelude::*;
+use crate::utils::{parse_choice, Choice, Pages};
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+/// The state of a [`PromptSession`] after providing an input.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Step<T> {
+    /// The session needs another input, with the rendered text of the field to display.
+    NeedInput(String),
+    /// The input is correct, and the value has been retrieved.
+    Done(T),
+}
+
+/// A field prompted by a session.
+trait SessionField<T> {
+    /// Returns the text to display before asking for an input.
+    fn render(&self) -> Result<String, fmt::Error>;
+
+    /// Returns the output value if the input is correct.
+    fn handle(&mut self, input: &str) -> Option<T>;
+}
+
+struct WrittenField<'a> {
+    written: &'a Written<'a>,
+}
+
+impl<T: FromStr> SessionField<T> for WrittenField<'_> {
+    fn render(&self) -> Result<String, fmt::Error> {
+        let mut s = String::new();
+        self.written.fmt_with(&mut s, &self.written.fmt, false)?;
+        s.write_str(self.written.fmt.suffix)?;
+        Ok(s)
+    }
+
+    fn handle(&mut self, input: &str) -> Option<T> {
+        self.written.parse_input(input)
+    }
+}
+
+struct SelectedField<'a, T, const N: usize> {
+    // The field is taken when the input is correct.
+    sel: Option<Selected<'a, T, N>>,
+    pages: Pages,
+}
+
+impl<T, const N: usize> SessionField<T> for SelectedField<'_, T, N> {
+    fn render(&self) -> Result<String, fmt::Error> {
+        let mut s = String::new();
+        if let Some(ref sel) = self.sel {
+            sel.fmt_page(&mut s, &sel.fmt, &self.pages, false)?;
+            s.write_str(sel.fmt.suffix)?;
+        }
+        Ok(s)
+    }
+
+    fn handle(&mut self, input: &str) -> Option<T> {
+        let sel = self.sel.as_ref()?;
+        let i = match parse_choice(input, &sel.fmt, sel.len(), self.pages.is_paged(), &[]) {
+            Choice::Index(i) => Some(i),
+            Choice::Next => {
+                self.pages.next();
+                None
+            }
+            Choice::Prev => {
+                self.pages.prev();
+                None
+            }
+            _ => sel.default_index(),
+        }?;
+
+        // SAFETY: the index is either parsed in bounds, or the default index in bounds.
+        self.sel.take().map(|sel| unsafe { sel.take(i) })
+    }
+}
+
+/// Returns the error used when the value of the session has already been retrieved.
+fn retrieved() -> MenuError {
+    "the value of the prompt session has already been retrieved".into()
+}
+
+/// A session used to retrieve a value from a field, without reading from a stream.
+///
+/// It renders the field as text, then the caller provides the inputs of the user
+/// until the value is retrieved. The inputs are handled like with a [`MenuStream`],
+/// so a graphical or a network frontend can reuse the same parsing and formatting logic
+/// without any terminal.
+///
+/// # Example
+///
+/// ```
+/// # use ezmenulib::prelude::*;
+/// # fn main() -> MenuResult {
+/// let age = Written::from("How old are you?");
+/// let mut session = PromptSession::<u8>::written(&age);
+/// assert_eq!(session.text()?, "--> How old are you?\n>> ");
+///
+/// assert_eq!(
+///     session.provide("abc")?,
+///     Step::NeedInput("--> How old are you?\n>> ".to_owned())
+/// );
+/// assert_eq!(session.provide("19")?, Step::Done(19));
+/// # Ok(()) }
+/// ```
+pub struct PromptSession<'a, T> {
+    // The field is removed when the value has been retrieved.
+    field: Option<Box<dyn SessionField<T> + 'a>>,
+}
+
+impl<T> fmt::Debug for PromptSession<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PromptSession")
+            .field("done", &self.field.is_none())
+            .finish()
+    }
+}
+
+impl<'a, T: FromStr + 'a> PromptSession<'a, T> {
+    /// Returns the session retrieving a value from the written field.
+    ///
+    /// An input is handled like with the [`Written::optional_value`] function: the default
+    /// value of the field is used if the input is incorrect.
+    ///
+    /// # Panics
+    ///
+    /// If the default value has an incorrect type, providing an input may panic.
+    pub fn written(written: &'a Written<'a>) -> Self {
+        Self {
+            field: Some(Box::new(WrittenField { written })),
+        }
+    }
+}
+
+impl<'a, T: 'a> PromptSession<'a, T> {
+    /// Returns the session retrieving a value from the selectable field.
+    ///
+    /// An input is handled like with the [`Selected::optional_select`] function: the value
+    /// at the default index is used if the input is incorrect. If the list is paged,
+    /// providing `n` or `p` updates the rendered text with the next or the previous page.
+    pub fn selected<const N: usize>(sel: Selected<'a, T, N>) -> Self {
+        let pages = Pages::new(sel.fmt.page_size, sel.len());
+        Self {
+            field: Some(Box::new(SelectedField {
+                sel: Some(sel),
+                pages,
+            })),
+        }
+    }
+
+    /// Returns the rendered text of the field, to display before asking for an input.
+    ///
+    /// It returns an error if the value has already been retrieved.
+    pub fn text(&self) -> MenuResult<String> {
+        Ok(self.field.as_ref().ok_or_else(retrieved)?.render()?)
+    }
+
+    /// Provides the input of the user to the session.
+    ///
+    /// It returns the retrieved value if the input is correct, otherwise the text to display
+    /// before asking for another input. It returns an error if the value has already been
+    /// retrieved.
+    pub fn provide<S: AsRef<str>>(&mut self, input: S) -> MenuResult<Step<T>> {
+        let field = self.field.as_mut().ok_or_else(retrieved)?;
+        Ok(match field.handle(input.as_ref().trim()) {
+            Some(out) => {
+                self.field = None;
+                Step::Done(out)
+            }
+            None => Step::NeedInput(field.render()?),
+        })
+    }
+}
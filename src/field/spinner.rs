@@ -0,0 +1,243 @@
+//! Module defining the numeric spinner, retrieving a number among a range.
+
+use crate::i18n::tr_label;
+use crate::prelude::*;
+use crate::utils::prompt;
+#[cfg(feature = "crossterm")]
+use crate::utils::Wait;
+use crate::DEFAULT_FMT;
+use std::fmt::{self, Display, Formatter};
+use std::io::{BufRead, Write};
+use std::ops::RangeInclusive;
+#[cfg(feature = "crossterm")]
+use std::time::Duration;
+
+/// Defines a numeric input bounded by a range, like a port number or a count.
+///
+/// With a stream using the [arrow keys](MenuStream::arrows), the value is adjusted
+/// in raw mode: `Up` and `Down` add or subtract the step, the digits can be typed directly,
+/// and `Enter` confirms the value. The value is displayed in the error style while it is out
+/// of the range. `Esc` restores the default value, and `Ctrl-C` or `Ctrl-D` cancels the prompt.
+///
+/// Otherwise, or if the terminal is dumb (`TERM=dumb`), the value is written like
+/// a [written field](Written), until it is in the range.
+///
+/// # Example
+///
+/// ```no_run
+/// # use ezmenulib::prelude::*;
+/// # fn main() -> MenuResult {
+/// let port = Spinner::new("Port", 1..=65535)
+///     .default_value(8080)
+///     .step(10)
+///     .prompt(&mut MenuStream::default())?;
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Spinner<'a> {
+    msg: &'a str,
+    /// The format of the spinner.
+    pub fmt: Format<'a>,
+    range: RangeInclusive<i64>,
+    step: i64,
+    default: Option<i64>,
+    #[cfg(feature = "crossterm")]
+    timeout: Option<Duration>,
+}
+
+impl<'a> Spinner<'a> {
+    /// Returns the spinner with the given message, retrieving a number in the given range.
+    ///
+    /// If the range is inverted, like `10..=1`, its bounds are swapped.
+    pub fn new(msg: &'a str, range: RangeInclusive<i64>) -> Self {
+        let (start, end) = range.into_inner();
+        Self {
+            msg,
+            fmt: Format::default(),
+            range: start.min(end)..=start.max(end),
+            step: 1,
+            default: None,
+            #[cfg(feature = "crossterm")]
+            timeout: None,
+        }
+    }
+
+    /// Defines the format of the spinner.
+    pub fn format(mut self, fmt: Format<'a>) -> Self {
+        self.fmt = fmt;
+        self
+    }
+
+    /// Defines the amount added or subtracted with the arrow keys (`1` by default).
+    pub fn step(mut self, step: i64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Defines the default value, used if the user enters nothing.
+    ///
+    /// It is clamped to the range of the spinner.
+    pub fn default_value(mut self, default: i64) -> Self {
+        self.default = Some(default.clamp(*self.range.start(), *self.range.end()));
+        self
+    }
+
+    /// Defines the time the user has to press a key before the default value is returned.
+    ///
+    /// If there is no default value, the prompt returns a [`MenuError::Timeout`] error.
+    /// The timeout stops once the user pressed a key.
+    ///
+    /// It only applies if the value is adjusted in raw mode, as the blocking reads
+    /// of the [`BufRead`] reader of the stream cannot be interrupted.
+    #[cfg(feature = "crossterm")]
+    #[cfg_attr(nightly, doc(cfg(feature = "crossterm")))]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Returns the value if the input is a number in the range.
+    fn parse(&self, input: &str) -> Option<i64> {
+        input.parse().ok().filter(|n| self.range.contains(n))
+    }
+
+    /// Displays the message with the range and the default value.
+    fn fmt_msg<S: fmt::Write>(&self, s: &mut S, fmt: &Format<'_>) -> fmt::Result {
+        write!(
+            s,
+            "{}{} (from {} to {}",
+            fmt.style.prefix(fmt.prefix),
+            self.msg,
+            self.range.start(),
+            self.range.end()
+        )?;
+        match self.default {
+            Some(d) if fmt.show_default && fmt.verbosity != Verbosity::Minimal => {
+                let label = tr_label(
+                    "ezmenulib.default",
+                    fmt.default_label,
+                    DEFAULT_FMT.default_label,
+                );
+                write!(s, ", {}: {})", label, fmt.style.default_value(d))?
+            }
+            _ => s.write_char(')')?,
+        }
+        s.write_char('\n')
+    }
+
+    /// Prompts the spinner with the given format, until the value is in the range.
+    ///
+    /// It uses the merged version between the format of the spinner and the given format.
+    pub fn prompt_with<R: BufRead, W: Write>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        fmt: &Format<'a>,
+    ) -> MenuResult<i64> {
+        let fmt = stream.output_format(&self.fmt.merged(fmt));
+        self.fmt_msg(stream, &fmt)?;
+
+        #[cfg(feature = "crossterm")]
+        if stream.is_arrows() && std::env::var("TERM").as_deref() != Ok("dumb") {
+            return self.prompt_raw(stream, &fmt);
+        }
+
+        // Loops while incorrect input.
+        loop {
+            let input = prompt(fmt.suffix, stream)?;
+            match (self.default, self.parse(&input)) {
+                (Some(d), _) if input.is_empty() => return Ok(d),
+                (_, Some(n)) => return Ok(n),
+                _ => (),
+            }
+        }
+    }
+
+    /// Prompts the spinner until the value is in the range.
+    pub fn prompt<R: BufRead, W: Write>(&self, stream: &mut MenuStream<R, W>) -> MenuResult<i64> {
+        self.prompt_with(stream, &self.fmt)
+    }
+
+    /// Lets the user adjust the value in raw mode, until the value in the range is confirmed.
+    #[cfg(feature = "crossterm")]
+    fn prompt_raw<W: Write>(&self, writer: &mut W, fmt: &Format<'_>) -> MenuResult<i64> {
+        use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+
+        enable_raw_mode()?;
+        let out = self.raw_loop(writer, fmt);
+        // The raw mode is disabled even if drawing the value failed.
+        disable_raw_mode()?;
+        out
+    }
+
+    #[cfg(feature = "crossterm")]
+    fn raw_loop<W: Write>(&self, writer: &mut W, fmt: &Format<'_>) -> MenuResult<i64> {
+        use crossterm::{
+            event::{read, Event, KeyCode, KeyEvent, KeyModifiers},
+            queue,
+            terminal::{Clear, ClearType},
+        };
+
+        let (min, max) = (*self.range.start(), *self.range.end());
+        let start = self.default.unwrap_or(min);
+        let mut input = start.to_string();
+        let mut wait = Wait::timeout(self.timeout);
+
+        loop {
+            queue!(writer, Clear(ClearType::CurrentLine))?;
+            match self.parse(&input) {
+                Some(_) => write!(writer, "\r{}{}", fmt.suffix, input)?,
+                None => write!(writer, "\r{}{}", fmt.suffix, fmt.style.error(&input))?,
+            }
+            writer.flush()?;
+
+            if !wait.poll()? {
+                write!(writer, "\r\n")?;
+                return self.default.ok_or(MenuError::Timeout);
+            }
+            if let Event::Key(KeyEvent { code, modifiers }) = read()? {
+                // The timeout stops once the user pressed a key.
+                wait.deadline = None;
+                let current = input.parse().unwrap_or(start);
+                match code {
+                    KeyCode::Up => {
+                        input = current
+                            .saturating_add(self.step)
+                            .clamp(min, max)
+                            .to_string()
+                    }
+                    KeyCode::Down => {
+                        input = current
+                            .saturating_sub(self.step)
+                            .clamp(min, max)
+                            .to_string()
+                    }
+                    KeyCode::Enter => {
+                        if let Some(n) = self.parse(&input) {
+                            write!(writer, "\r\n")?;
+                            return Ok(n);
+                        }
+                    }
+                    KeyCode::Esc => input = start.to_string(),
+                    KeyCode::Backspace => {
+                        input.pop();
+                    }
+                    KeyCode::Char('c' | 'd') if modifiers.contains(KeyModifiers::CONTROL) => {
+                        write!(writer, "\r\n")?;
+                        return Err(MenuError::Cancelled);
+                    }
+                    KeyCode::Char(c) if c.is_ascii_digit() || c == '-' && input.is_empty() => {
+                        input.push(c)
+                    }
+                    _ => (),
+                }
+            }
+        }
+    }
+}
+
+impl Display for Spinner<'_> {
+    /// Displays the message with the range and the default value.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_msg(f, &self.fmt)
+    }
+}
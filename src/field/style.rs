@@ -0,0 +1,106 @@
+//! Module defining the style of the fields, used to color the raw prompts.
+//!
+//! The style is applied with ANSI escape codes only if the `"color"` feature is enabled,
+//! and if the stream is colored (see [`MenuStream::colored`](crate::menu::MenuStream::colored)).
+
+use std::fmt::{self, Display, Formatter};
+
+/// A terminal color, used by the [`Style`] of a [`Format`](crate::field::Format).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    /// The black color.
+    Black,
+    /// The red color.
+    Red,
+    /// The green color.
+    Green,
+    /// The yellow color.
+    Yellow,
+    /// The blue color.
+    Blue,
+    /// The magenta color.
+    Magenta,
+    /// The cyan color.
+    Cyan,
+    /// The white color.
+    White,
+}
+
+impl Color {
+    /// Returns the ANSI code of the foreground color.
+    fn code(self) -> u8 {
+        30 + self as u8
+    }
+}
+
+/// Defines the colors of the raw prompts.
+///
+/// It is used by the [`Format::style`](crate::field::Format::style) specification.
+///
+/// # Example
+///
+/// ```
+/// # use ezmenulib::prelude::*;
+/// let fmt = Format::style(Style {
+///     prefix: Some(Color::Cyan),
+///     error: Some(Color::Red),
+///     dim_default: true,
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Style {
+    /// The color of the prefix (`None` by default).
+    pub prefix: Option<Color>,
+    /// The color of the error messages, e.g. when a value is not validated (`None` by default).
+    pub error: Option<Color>,
+    /// Defines if the default values are dimmed (`false` by default).
+    pub dim_default: bool,
+}
+
+impl Style {
+    /// The style without any color.
+    pub const PLAIN: Self = Self {
+        prefix: None,
+        error: None,
+        dim_default: false,
+    };
+
+    pub(crate) fn prefix<T: Display>(&self, text: T) -> Painted<T> {
+        Painted {
+            text,
+            code: self.prefix.map(Color::code),
+        }
+    }
+
+    pub(crate) fn error<T: Display>(&self, text: T) -> Painted<T> {
+        Painted {
+            text,
+            code: self.error.map(Color::code),
+        }
+    }
+
+    pub(crate) fn default_value<T: Display>(&self, text: T) -> Painted<T> {
+        Painted {
+            text,
+            // The ANSI code for the faint intensity.
+            code: self.dim_default.then_some(2),
+        }
+    }
+}
+
+/// A text displayed with an ANSI code, if any.
+pub(crate) struct Painted<T> {
+    text: T,
+    code: Option<u8>,
+}
+
+impl<T: Display> Display for Painted<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.code {
+            Some(code) if cfg!(feature = "color") => {
+                write!(f, "\x1b[{}m{}\x1b[0m", code, self.text)
+            }
+            _ => self.text.fmt(f),
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//! Module defining the table selection, selecting a row among columnar data.
+
+use crate::prelude::*;
+use crate::utils::{check_fields, select, Choice, Pages};
+use std::fmt::{self, Display, Formatter, Write as _};
+use std::io::{BufRead, Write};
+
+/// Defines a field selecting a row among columnar data, like servers, containers
+/// or accounts with multiple attributes.
+///
+/// The rows are displayed like the [selectable values](Selected), with their columns aligned
+/// under the headers, and the list can be paged (see [`Format::page_size`]).
+/// Selecting a row returns its index, starting from `0`.
+///
+/// # Example
+///
+/// ```
+/// # use ezmenulib::prelude::*;
+/// let servers = TableSelect::new(
+///     "Select a server",
+///     &["NAME", "IP", "STATE"],
+///     [
+///         ["web-1", "10.0.0.1", "running"],
+///         ["database", "10.0.0.12", "stopped"],
+///     ],
+/// );
+///
+/// assert_eq!(
+///     servers.to_string(),
+///     "--> Select a server
+///       NAME      IP         STATE
+/// [1] - web-1     10.0.0.1   running
+/// [2] - database  10.0.0.12  stopped
+/// "
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct TableSelect<'a> {
+    msg: &'a str,
+    /// The format of the table selection.
+    pub fmt: Format<'a>,
+    headers: &'a [&'a str],
+    rows: Vec<Vec<String>>,
+}
+
+impl<'a> TableSelect<'a> {
+    /// Returns the table selection with the given message, column headers and rows.
+    ///
+    /// Each row is a list of cells, displayed with their [`Display`] implementation.
+    ///
+    /// # Panic
+    ///
+    /// If there is no row, this function will panic (see [`Selected::new`]).
+    pub fn new<I, R>(msg: &'a str, headers: &'a [&'a str], rows: I) -> Self
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator,
+        R::Item: Display,
+    {
+        let rows: Vec<Vec<String>> = rows
+            .into_iter()
+            .map(|row| row.into_iter().map(|cell| cell.to_string()).collect())
+            .collect();
+        check_fields(&rows);
+
+        Self {
+            msg,
+            fmt: Format::default(),
+            headers,
+            rows,
+        }
+    }
+
+    /// Defines the format of the table selection.
+    pub fn format(mut self, fmt: Format<'a>) -> Self {
+        self.fmt = fmt;
+        self
+    }
+
+    /// Returns the width of each column, according to its header and its cells.
+    fn widths(&self) -> Vec<usize> {
+        let mut widths: Vec<usize> = self.headers.iter().map(|h| h.chars().count()).collect();
+        for row in &self.rows {
+            for (i, cell) in row.iter().enumerate() {
+                let width = cell.chars().count();
+                match widths.get_mut(i) {
+                    Some(w) => *w = (*w).max(width),
+                    None => widths.push(width),
+                }
+            }
+        }
+        widths
+    }
+
+    /// Writes the cells padded to the width of their column, without trailing spaces.
+    fn fmt_cells<'c, S: fmt::Write>(
+        s: &mut S,
+        cells: impl Iterator<Item = &'c str>,
+        widths: &[usize],
+    ) -> fmt::Result {
+        let mut line = String::new();
+        for (cell, width) in cells.zip(widths) {
+            write!(line, "{:width$}  ", cell)?;
+        }
+        writeln!(s, "{}", line.trim_end())
+    }
+
+    /// Displays the message with the headers and the rows of the current page.
+    fn fmt_page<S: fmt::Write>(&self, s: &mut S, fmt: &Format<'_>, pages: &Pages) -> fmt::Result {
+        writeln!(s, "{}{}", fmt.style.prefix(fmt.prefix), self.msg)?;
+
+        // The indexes are padded, so the columns stay aligned after the 9th row.
+        let index_width = (0..self.rows.len())
+            .map(|i| fmt.index(i).chars().count())
+            .max()
+            .unwrap_or(0);
+        let widths = self.widths();
+
+        if !self.headers.is_empty() {
+            let indent = index_width + fmt.chip.chars().count();
+            write!(s, "{:indent$}", "")?;
+            Self::fmt_cells(s, self.headers.iter().copied(), &widths)?;
+        }
+
+        for i in pages.range() {
+            write!(s, "{:index_width$}{}", fmt.index(i), fmt.chip)?;
+            Self::fmt_cells(s, self.rows[i].iter().map(String::as_str), &widths)?;
+        }
+
+        pages.fmt_controls(s, fmt)
+    }
+
+    /// Prompts the rows until the user selects one, using the given format,
+    /// and returns its index.
+    ///
+    /// It uses the merged version between the format of the table selection and the given format.
+    pub fn select_with<R: BufRead, W: Write>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        fmt: &Format<'a>,
+    ) -> MenuResult<usize> {
+        let fmt = stream.output_format(&self.fmt.merged(fmt));
+        let mut pages = Pages::new(fmt.page_size, self.rows.len());
+        self.fmt_page(stream, &fmt, &pages)?;
+
+        loop {
+            match select(
+                stream,
+                &fmt,
+                fmt.suffix,
+                self.rows.len(),
+                pages.is_paged(),
+                &[],
+            )? {
+                Choice::Index(i) => return Ok(i),
+                Choice::Next if pages.next() => self.fmt_page(stream, &fmt, &pages)?,
+                Choice::Prev if pages.prev() => self.fmt_page(stream, &fmt, &pages)?,
+                _ => (),
+            }
+        }
+    }
+
+    /// Prompts the rows until the user selects one, and returns its index.
+    pub fn select<R: BufRead, W: Write>(&self, stream: &mut MenuStream<R, W>) -> MenuResult<usize> {
+        self.select_with(stream, &self.fmt)
+    }
+}
+
+impl Display for TableSelect<'_> {
+    /// Displays the message with the headers and the rows of the first page.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_page(
+            f,
+            &self.fmt,
+            &Pages::new(self.fmt.page_size, self.rows.len()),
+        )
+    }
+}
@@ -6,3 +6,128 @@ fn fmt_merge() {
     let new = fmt.merged(&Format::suffix("> "));
     assert_eq!(new.suffix, "--> ");
 }
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_defaults() -> crate::MenuResult {
+    use crate::field::{DefaultsProvider, TomlDefaults};
+
+    let defaults = TomlDefaults::from_toml("name = \"Ahmad\"\n[db]\nport = 5432\n")?;
+    assert_eq!(defaults.get("name").as_deref(), Some("Ahmad"));
+    assert_eq!(defaults.get("db.port").as_deref(), Some("5432"));
+    assert_eq!(defaults.get("db"), None);
+    assert_eq!(defaults.get("db.host"), None);
+    Ok(())
+}
+
+#[test]
+fn prompt_session() -> crate::MenuResult {
+    use crate::field::{PromptSession, Selected, Step};
+
+    let mut session = PromptSession::selected(
+        Selected::new("select", [("a", 0), ("b", 1), ("c", 2)]).format(Format::page_size(Some(2))),
+    );
+    assert_eq!(
+        session.text()?,
+        "--> select\n[1] - a\n[2] - b\n[n] - next page\n>> "
+    );
+    assert_eq!(
+        session.provide("n")?,
+        Step::NeedInput("--> select\n[3] - c\n[p] - previous page\n>> ".to_owned())
+    );
+    assert_eq!(session.provide("3")?, Step::Done(2));
+    assert!(session.provide("1").is_err());
+
+    let mut session = PromptSession::selected(Selected::from_iter("select", [10, 20]));
+    assert_eq!(session.provide("2")?, Step::Done(20));
+    Ok(())
+}
+
+#[test]
+fn docs_attempts() -> crate::MenuResult {
+    use crate::{field::Written, menu::MenuStream};
+
+    // Asking for the documentation is not an incorrect input.
+    let mut input = "?!\n4\n?!\n5\n?!\n6\n?!\n1,2\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut stream = MenuStream::with(&mut input, &mut output);
+    let written = Written::from("workers")
+        .docs("https://example.com/workers")
+        .max_attempts(1);
+
+    assert_eq!(written.prompt::<_, _, u8>(&mut stream)?, 4);
+    assert_eq!(
+        written.prompt_validated(&mut stream, |_: &u8| Ok::<_, &str>(()))?,
+        5
+    );
+    assert_eq!(written.optional_value::<_, _, u8>(&mut stream)?, Some(6));
+    assert_eq!(
+        written.many_values::<_, _, u8, _>(&mut stream, ",")?,
+        vec![1, 2]
+    );
+    drop(stream);
+
+    let mut input = "?!\n7\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut stream = MenuStream::with(&mut input, &mut output);
+    assert_eq!(
+        written.prompt_parsed(&mut stream, |s| s.parse::<u8>().map_err(|_| "incorrect"))?,
+        7
+    );
+    Ok(())
+}
+
+#[cfg(feature = "crossterm")]
+#[test]
+fn arrows_window() {
+    use crate::utils::{scroll_offset, window_size};
+
+    // Without page size, all the values are drawn.
+    let size = window_size(None, 5);
+    assert_eq!(size, 5);
+    assert_eq!(scroll_offset(0, 0, size), 0);
+    assert_eq!(scroll_offset(0, 2, size), 0);
+    assert_eq!(scroll_offset(0, 4, size), 0);
+
+    let size = window_size(Some(3), 5);
+    assert_eq!(size, 3);
+    assert_eq!(scroll_offset(0, 0, size), 0);
+    assert_eq!(scroll_offset(0, 2, size), 0);
+    // Scrolls down to the last value, then up to the first one.
+    assert_eq!(scroll_offset(0, 4, size), 2);
+    assert_eq!(scroll_offset(2, 3, size), 2);
+    assert_eq!(scroll_offset(2, 1, size), 1);
+    assert_eq!(scroll_offset(1, 0, size), 0);
+
+    assert_eq!(window_size(Some(0), 5), 1);
+    assert_eq!(window_size(Some(10), 5), 5);
+}
+
+#[cfg(feature = "fluent")]
+#[test]
+fn fluent_localizer() {
+    use crate::i18n::Localizer;
+    use fluent::{concurrent::FluentBundle, FluentResource};
+
+    let res = FluentResource::try_new(
+        "ezmenulib =\n    .default = défaut\n    .incorrect = '{ $value }' est incorrect\napp = Votre âge\n"
+            .to_owned(),
+    )
+    .unwrap();
+    let mut bundle = FluentBundle::new_concurrent(Vec::new());
+    bundle.set_use_isolating(false);
+    bundle.add_resource(res).unwrap();
+
+    assert_eq!(bundle.localize("app", &[]).as_deref(), Some("Votre âge"));
+    assert_eq!(
+        bundle.localize("ezmenulib.default", &[]).as_deref(),
+        Some("défaut")
+    );
+    assert_eq!(
+        bundle
+            .localize("ezmenulib.incorrect", &[("value", "abc")])
+            .as_deref(),
+        Some("'abc' est incorrect")
+    );
+    assert_eq!(bundle.localize("ezmenulib.optional", &[]), None);
+}
@@ -0,0 +1,112 @@
+//! Module defining the themes of the menus.
+
+use crate::field::{Color, Format, Style};
+use crate::DEFAULT_FMT;
+
+#[cfg(all(feature = "tui", any(feature = "crossterm", feature = "termion")))]
+use crate::tui::FieldStyle;
+#[cfg(all(feature = "tui", any(feature = "crossterm", feature = "termion")))]
+use tui::style::{Color as TuiColor, Modifier, Style as TuiStyle};
+
+/// Produces the whole format and styles of the menus.
+///
+/// A theme can be applied globally on a [`Values`](crate::menu::Values) container,
+/// a [`RawMenu`](crate::menu::RawMenu) or a [`TuiMenu`](crate::tui::TuiMenu),
+/// with their `theme` associated function.
+///
+/// # Example
+///
+/// ```no_run
+/// # use ezmenulib::prelude::*;
+/// # fn main() -> MenuResult {
+/// let mut menu = Values::default().theme(Fancy);
+/// let name: String = menu.written(&Written::from("What is your name?"))?;
+/// # Ok(()) }
+/// ```
+pub trait Theme {
+    /// Returns the format of the raw prompts and menus.
+    fn format(&self) -> Format<'static>;
+
+    /// Returns the styles of the selected field and of the other fields of the tui menus.
+    #[cfg(all(feature = "tui", any(feature = "crossterm", feature = "termion")))]
+    #[cfg_attr(nightly, doc(cfg(feature = "tui")))]
+    fn tui_styles(&self) -> (FieldStyle, FieldStyle) {
+        crate::tui::default_styles()
+    }
+}
+
+/// The default theme, without any color.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Plain;
+
+impl Theme for Plain {
+    fn format(&self) -> Format<'static> {
+        DEFAULT_FMT
+    }
+}
+
+/// A colored theme.
+///
+/// The colors of the raw prompts are only displayed if the `"color"` feature is enabled
+/// (see [`Style`]).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Fancy;
+
+impl Theme for Fancy {
+    fn format(&self) -> Format<'static> {
+        Format {
+            prefix: "❯ ",
+            left_sur: "",
+            right_sur: ")",
+            chip: " ",
+            suffix: "» ",
+            style: Style {
+                prefix: Some(Color::Cyan),
+                error: Some(Color::Red),
+                dim_default: true,
+            },
+            ..DEFAULT_FMT
+        }
+    }
+
+    #[cfg(all(feature = "tui", any(feature = "crossterm", feature = "termion")))]
+    fn tui_styles(&self) -> (FieldStyle, FieldStyle) {
+        (
+            (
+                TuiStyle::default()
+                    .add_modifier(Modifier::BOLD)
+                    .fg(TuiColor::Black),
+                TuiColor::Cyan,
+            ),
+            (TuiStyle::default().fg(TuiColor::Cyan), TuiColor::Reset),
+        )
+    }
+}
+
+/// A theme displaying as few characters as possible.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Minimal;
+
+impl Theme for Minimal {
+    fn format(&self) -> Format<'static> {
+        Format {
+            prefix: "",
+            left_sur: "",
+            right_sur: "",
+            chip: ". ",
+            suffix: "> ",
+            ..DEFAULT_FMT
+        }
+    }
+
+    #[cfg(all(feature = "tui", any(feature = "crossterm", feature = "termion")))]
+    fn tui_styles(&self) -> (FieldStyle, FieldStyle) {
+        (
+            (
+                TuiStyle::default().add_modifier(Modifier::REVERSED),
+                TuiColor::Reset,
+            ),
+            (TuiStyle::default(), TuiColor::Reset),
+        )
+    }
+}
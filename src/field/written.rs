@@ -0,0 +1,1173 @@
+//! Module defining the written fields, retrieving a value typed by the user.
+
+use crate::i18n::{tr, tr_label, tr_msg};
+use crate::prelude::*;
+use crate::utils::*;
+use crate::DEFAULT_FMT;
+use std::any::type_name;
+use std::borrow::Cow;
+use std::env;
+use std::fmt::{self, Display, Formatter};
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Defines the behavior for a written value provided by the user.
+///
+/// Like the [selected](Selected) values, it contains its own [format](Format),
+/// and it can be inherited, saving the custom format specifications.
+///
+/// It displays the message, with a given example and default value if it is provided
+/// (see [`Written::example`] or [`Written::default_value`] functions).
+///
+/// It provides functions to define how to retrieve the value from the user.
+/// You have to provide a mutable reference to a [`MenuStream`] to retrieve the value.
+///
+/// # Example
+///
+/// For a make-license CLI program for example, you can use it like below:
+///
+/// ```no_run
+/// use ezmenulib::prelude::*;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let author: Vec<String> = Written::from("Give the authors of the license")
+///     .many_values(&mut MenuStream::default(), ", ")?;
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct Written<'a> {
+    pub(crate) msg: &'a str,
+    /// The format of the written field value.
+    pub fmt: Format<'a>,
+    example: Option<String>,
+    unit: Option<&'a str>,
+    suggestions: &'a [&'a str],
+    default: Option<String>,
+    // The name of the type of the default value, if it has been given as a typed value.
+    default_type: Option<&'static str>,
+    strict: bool,
+    max_attempts: Option<usize>,
+    backoff: Option<Duration>,
+    docs: Option<&'a str>,
+    i18n: Option<&'a str>,
+    #[cfg(feature = "crossterm")]
+    timeout: Option<Duration>,
+}
+
+impl<'a> From<&'a str> for Written<'a> {
+    fn from(msg: &'a str) -> Self {
+        Self {
+            msg,
+            fmt: Format::default(),
+            example: None,
+            unit: None,
+            suggestions: &[],
+            default: None,
+            default_type: None,
+            strict: false,
+            max_attempts: None,
+            backoff: None,
+            docs: None,
+            i18n: None,
+            #[cfg(feature = "crossterm")]
+            timeout: None,
+        }
+    }
+}
+
+impl Display for Written<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_with(f, &self.fmt, false)?;
+        f.write_str(match self.fmt.line_brk {
+            true => "\n",
+            false => self.fmt.suffix,
+        })
+    }
+}
+
+/// Constructor methods defining how the field behaves
+impl<'a> Written<'a> {
+    /// Displays the message of the written field with a given prefix.
+    ///
+    /// This is used to prompt the written field with a given [`Format`]
+    /// (see [`Written::prompt_with`] function for example).
+    pub(crate) fn fmt_with<S: fmt::Write>(
+        &self,
+        s: &mut S,
+        fmt: &Format<'_>,
+        opt: bool,
+    ) -> fmt::Result {
+        let msg = tr_msg(self.i18n, self.msg);
+        write!(s, "{}{}", fmt.style.prefix(fmt.prefix), msg)?;
+
+        // Field details
+        let details = [
+            self.unit
+                .map(|u| format!("{}: {}", tr("ezmenulib.unit", "unit", &[]), u)),
+            self.example.as_ref().filter(|_| fmt.show_example).map(|e| {
+                let label = tr_label(
+                    "ezmenulib.example",
+                    fmt.example_label,
+                    DEFAULT_FMT.example_label,
+                );
+                format!("{}: {}", label, e)
+            }),
+            match self.default {
+                Some(ref d) if fmt.show_default => {
+                    let label = tr_label(
+                        "ezmenulib.default",
+                        fmt.default_label,
+                        DEFAULT_FMT.default_label,
+                    );
+                    Some(format!("{}: {}", label, fmt.style.default_value(d)))
+                }
+                _ => None,
+            },
+            (opt && self.default.is_none() && fmt.show_optional).then(|| {
+                tr_label(
+                    "ezmenulib.optional",
+                    fmt.optional_label,
+                    DEFAULT_FMT.optional_label,
+                )
+                .into_owned()
+            }),
+        ];
+        let shown = match fmt.verbosity {
+            Verbosity::Minimal => &details[..1],
+            _ => &details[..],
+        };
+        let mut details = shown.iter().flatten();
+        if let Some(first) = details.next() {
+            write!(s, " ({}", first)?;
+            for detail in details {
+                write!(s, ", {}", detail)?;
+            }
+            s.write_str(")")?;
+        }
+
+        match fmt.line_brk {
+            true => {
+                s.write_char('\n')?;
+                match self.help(opt) {
+                    Some(help) if fmt.verbosity == Verbosity::Verbose => writeln!(s, "{}", help),
+                    _ => Ok(()),
+                }
+            }
+            false => Ok(()),
+        }
+    }
+
+    /// Returns the help line explaining how to answer the field, displayed
+    /// with the [`Verbosity::Verbose`] level.
+    fn help(&self, opt: bool) -> Option<Cow<'static, str>> {
+        let (key, help) = match (&self.default, &self.example) {
+            (Some(_), _) => (
+                "ezmenulib.help-default",
+                "Press Enter to use the default value.",
+            ),
+            (None, _) if opt => ("ezmenulib.help-skip", "Press Enter to skip this field."),
+            (None, Some(_)) => ("ezmenulib.help-example", "Enter a value like the example."),
+            (None, None) => return None,
+        };
+        Some(tr(key, help, &[]))
+    }
+
+    fn first_line<R, W: Write>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        fmt: &Format<'_>,
+        opt: bool,
+    ) -> MenuResult {
+        if fmt.line_brk {
+            let fmt = stream.output_format(fmt);
+            self.fmt_with(stream, &fmt, opt)?;
+        }
+        Ok(())
+    }
+
+    /// Displays the second line according to the format, and returns the output
+    /// of the prompt.
+    ///
+    /// If the user asks for the [documentation](Written::docs) of the field, the link
+    /// is printed out and the field is prompted again, without counting an incorrect input.
+    fn prompt_line<R: BufRead, W: Write>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        fmt: &Format<'_>,
+        opt: bool,
+    ) -> MenuResult<String> {
+        loop {
+            if !fmt.line_brk {
+                let fmt = stream.output_format(fmt);
+                self.fmt_with(stream, &fmt, opt)?;
+            }
+
+            let s = self.read_line(stream, fmt)?;
+            match self.docs {
+                Some(url) if s == DOCS_INPUT => show_docs(stream, url)?,
+                _ => return Ok(s),
+            }
+        }
+    }
+
+    /// Prints out the suffix, then returns the line entered by the user.
+    ///
+    /// If the user did not press any key before the [timeout](Written::timeout),
+    /// it returns an empty line, so the default value is used.
+    fn read_line<R: BufRead, W: Write>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        fmt: &Format<'_>,
+    ) -> MenuResult<String> {
+        #[cfg(feature = "crossterm")]
+        if let (Some(timeout), true) = (self.timeout, stream.is_arrows()) {
+            show(fmt.suffix, stream)?;
+            return match read_timed(stream, Wait::timeout(Some(timeout)))? {
+                Some(s) => Ok(s),
+                None if self.default.is_some() => Ok(String::new()),
+                None => Err(MenuError::Timeout),
+            };
+        }
+        prompt(fmt.suffix, stream)
+    }
+
+    /// Gives a custom formatting for the written field.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ezmenulib::prelude::*;
+    /// let w = Written::from("hello").format(Format::prefix("==> "));
+    /// ```
+    pub fn format(mut self, fmt: Format<'a>) -> Self {
+        self.fmt = fmt;
+        self
+    }
+
+    /// Gives the default value accepted by the field.
+    ///
+    /// If the value type is incorrect, the [`Written::prompt`] function and its variations
+    /// will panic at runtime.
+    ///
+    /// The default value and the example (see the [`example`](Written::example) method documentation)
+    /// will be displayed inside parenthesis according to its formatting (see [`Format`]
+    /// for more information).
+    pub fn default_value(mut self, default: &'a str) -> Self {
+        self.default = Some(default.to_owned());
+        self.default_type = None;
+        self
+    }
+
+    /// Gives the default value accepted by the field, as a typed value.
+    ///
+    /// Unlike the [`Written::default_value`] function, the type of the default value
+    /// is checked at compile time, instead of parsing a string slice at runtime. The value
+    /// is stored with its [`Display`] implementation, which must be parsed back by its
+    /// [`FromStr`] implementation, like for the primitive types.
+    ///
+    /// The field must then be prompted with the same output type. Otherwise, or if the value
+    /// is not parsed back, the [`Written::prompt`] function and its variations return
+    /// a [`MenuError::Other`] error, instead of using a default value of another type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let (mut input, mut output) = ("\n".as_bytes(), Vec::new());
+    /// let mut stream = MenuStream::with(&mut input, &mut output);
+    /// let port: u16 = Written::from("Port").default_typed(8080u16).prompt(&mut stream)?;
+    /// assert_eq!(port, 8080);
+    /// # Ok(()) }
+    /// ```
+    pub fn default_typed<T: Display + FromStr>(mut self, default: T) -> Self {
+        self.default = Some(default.to_string());
+        self.default_type = Some(type_name::<T>());
+        self
+    }
+
+    /// Returns an error if the default value has been given as a typed value
+    /// of another type than `T`, or if it is not parsed back as a `T` value.
+    pub(crate) fn check_default<T: FromStr>(&self) -> MenuResult {
+        let (default, name) = match (&self.default, self.default_type) {
+            (Some(default), Some(name)) => (default, name),
+            _ => return Ok(()),
+        };
+        if name != type_name::<T>() {
+            return Err(format!(
+                "the default value `{}` of the field `{}` has the `{}` type, \
+                but the field is prompted with the `{}` type",
+                default,
+                self.msg,
+                name,
+                type_name::<T>()
+            )
+            .into());
+        }
+        match self.strip_unit(default).parse::<T>() {
+            Ok(_) => Ok(()),
+            Err(_) => Err(format!(
+                "the default value `{}` of the field `{}` is not parsed back as a `{}` value",
+                default, self.msg, name
+            )
+            .into()),
+        }
+    }
+
+    /// Gives the default value of the field, passed by an environment variable.
+    ///
+    /// If the provided environment variable is incorrect, it will return an error
+    /// (See [`MenuError::EnvVar`] variant).
+    ///
+    /// If the value type of the variable is incorrect, the [`Written::prompt`] function
+    /// and its variations will panic at runtime.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let user: String = Written::from("What is your name?")
+    ///     .default_env("USERNAME")?
+    ///     .prompt(&mut MenuStream::default())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn default_env(mut self, var: &'a str) -> MenuResult<Self> {
+        self.default = Some(env::var(var).map_err(|e| MenuError::EnvVar(var.to_owned(), e))?);
+        self.default_type = None;
+        Ok(self)
+    }
+
+    /// Gives the default value of the field, retrieved from the provider with the given key.
+    ///
+    /// If the provider has no value for this key, the default value remains unchanged.
+    ///
+    /// If the value type is incorrect, the [`Written::prompt`] function and its variations
+    /// will panic at runtime.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let user: String = Written::from("What is your name?")
+    ///     .default_from(&EnvDefaults::prefixed("APP_"), "USERNAME")
+    ///     .prompt(&mut MenuStream::default())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn default_from<P: DefaultsProvider + ?Sized>(mut self, provider: &P, key: &str) -> Self {
+        if let Some(default) = provider.get(key) {
+            self.default = Some(default);
+            self.default_type = None;
+        }
+        self
+    }
+
+    /// Gives an example of correct value for the field.
+    ///
+    /// Obviously, it is better to give a correct value for the user as example,
+    /// but if the value is incorrect, it will only mislead the user,
+    /// and unlike the default value providing, the program will not panic at runtime
+    /// to emphasize the problem.
+    ///
+    /// The example will be shown inside parenthesis according to its formatting
+    /// (see [`Format`] for more information).
+    pub fn example(mut self, example: &'a str) -> Self {
+        self.example = Some(example.to_owned());
+        self
+    }
+
+    /// Gives an example of correct value for the field, as a typed value.
+    ///
+    /// The value is displayed with its [`Display`] implementation, so the example
+    /// is formatted like the values of its type (see the [`Written::default_typed`] function
+    /// for the default value).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ezmenulib::prelude::*;
+    /// let field = Written::from("Timeout").example_typed(2.5f32).default_typed(10f32);
+    /// assert!(field
+    ///     .to_string()
+    ///     .starts_with("--> Timeout (example: 2.5, default: 10)\n"));
+    /// ```
+    pub fn example_typed<T: Display>(mut self, example: T) -> Self {
+        self.example = Some(example.to_string());
+        self
+    }
+
+    /// Gives the unit of the value of the field, e.g. `MB`.
+    ///
+    /// The unit will be shown inside parenthesis with the other details of the field,
+    /// e.g. `Size (unit: MB)` (see [`Format`] for more information). It is not displayed
+    /// after the suffix, because the input of the user is written right after it.
+    ///
+    /// The user may write the unit after the value, regardless of its case, and it is removed
+    /// from the input before parsing it. This also applies to the default value.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// // Both `512` and `512 MB` inputs are correct.
+    /// let size: u32 = Written::from("Size")
+    ///     .unit("MB")
+    ///     .prompt(&mut MenuStream::default())?;
+    /// # Ok(()) }
+    /// ```
+    pub fn unit(mut self, unit: &'a str) -> Self {
+        self.unit = Some(unit);
+        self
+    }
+
+    /// Gives the candidates suggested to the user when the input is incorrect.
+    ///
+    /// If the input is close to one of the candidates, e.g. because of a typo,
+    /// the closest candidate is suggested with the error style of the format
+    /// before prompting the field again.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// // If the user writes `stagng`, it prints "did you mean 'staging'?".
+    /// let env: Env = Written::from("Environment")
+    ///     .suggest_from(&["dev", "staging", "prod"])
+    ///     .prompt(&mut MenuStream::default())?;
+    /// # Ok(()) }
+    /// # enum Env { Dev, Staging, Prod }
+    /// # impl std::str::FromStr for Env {
+    /// #     type Err = ();
+    /// #     fn from_str(s: &str) -> Result<Self, ()> {
+    /// #         match s {
+    /// #             "dev" => Ok(Self::Dev),
+    /// #             "staging" => Ok(Self::Staging),
+    /// #             "prod" => Ok(Self::Prod),
+    /// #             _ => Err(()),
+    /// #         }
+    /// #     }
+    /// # }
+    /// ```
+    pub fn suggest_from(mut self, candidates: &'a [&'a str]) -> Self {
+        self.suggestions = candidates;
+        self
+    }
+
+    /// Defines if the default value only applies on empty input (`false` by default).
+    ///
+    /// By default, the default value is also used when the input is incorrect, which may hide
+    /// typos, e.g. typing `2O22` with a default value. In strict mode, an incorrect input
+    /// is rejected with an error message, and the field is prompted again.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let year: u16 = Written::from("Year")
+    ///     .default_value("2022")
+    ///     .strict(true)
+    ///     .prompt(&mut MenuStream::default())?;
+    /// # Ok(()) }
+    /// ```
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Defines the maximum amount of incorrect inputs before giving up (`None` by default).
+    ///
+    /// When the limit is reached, prompting the field returns a
+    /// [`MenuError::TooManyAttempts`] error, instead of prompting it again forever.
+    /// A value rejected by a constraint or a validation function also counts as an attempt.
+    pub fn max_attempts(mut self, max: usize) -> Self {
+        self.max_attempts = Some(max);
+        self
+    }
+
+    /// Defines the delay to wait after an incorrect input, before prompting the field again
+    /// (`None` by default).
+    ///
+    /// The delay doubles after each incorrect input, so sensitive prompts, like passwords
+    /// validated with the [`Written::prompt_validated`] function, resist rapid guessing,
+    /// e.g. when the stream is exposed over the network.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # use std::time::Duration;
+    /// # fn main() -> MenuResult {
+    /// let password: String = Written::from("Password")
+    ///     .backoff(Duration::from_millis(500))
+    ///     .max_attempts(5)
+    ///     .prompt_validated(&mut MenuStream::default(), |p: &String| match p.as_str() {
+    ///         "hunter2" => Ok(()),
+    ///         _ => Err("wrong password"),
+    ///     })?;
+    /// # Ok(()) }
+    /// ```
+    pub fn backoff(mut self, delay: Duration) -> Self {
+        self.backoff = Some(delay);
+        self
+    }
+
+    /// Defines the time the user has to press a key before the default value is used.
+    ///
+    /// If there is no default value, the prompt returns a [`MenuError::Timeout`] error.
+    /// The timeout stops once the user pressed a key, and it starts again each time
+    /// the field is prompted.
+    ///
+    /// It only applies if the stream reads the keys from the terminal (see [`MenuStream::arrows`]),
+    /// as the blocking reads of the [`BufRead`] reader of the stream cannot be interrupted.
+    /// The keys are then read in raw mode until the user starts typing.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # use std::time::Duration;
+    /// # fn main() -> MenuResult {
+    /// let entry: String = Written::from("Boot entry")
+    ///     .default_value("linux")
+    ///     .timeout(Duration::from_secs(5))
+    ///     .prompt(&mut MenuStream::default().arrows(true))?;
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "crossterm")]
+    #[cfg_attr(nightly, doc(cfg(feature = "crossterm")))]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Gives the link to the documentation of the field, e.g. for complicated
+    /// configuration questions.
+    ///
+    /// If the user enters `?!`, the link is printed out, then the field is prompted again.
+    /// If the `"open-docs"` feature is enabled, the link is also opened with the default
+    /// program of the system, e.g. a web browser.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let policy: String = Written::from("Retention policy")
+    ///     .docs("https://example.com/docs/retention")
+    ///     .prompt(&mut MenuStream::default())?;
+    /// # Ok(()) }
+    /// ```
+    pub fn docs(mut self, url: &'a str) -> Self {
+        self.docs = Some(url);
+        self
+    }
+
+    /// Defines the key of the message, translated by the installed [localizer](crate::i18n).
+    ///
+    /// The message is displayed as is if there is no localizer, or if it has no translation
+    /// for the key.
+    pub fn i18n(mut self, key: &'a str) -> Self {
+        self.i18n = Some(key);
+        self
+    }
+
+    /// Removes the unit of the field, if any, at the end of the given input.
+    ///
+    /// The unit is matched regardless of its case and of the surrounding spaces.
+    pub(crate) fn strip_unit<'s>(&self, s: &'s str) -> &'s str {
+        let unit = match self.unit.map(str::trim) {
+            Some(unit) if !unit.is_empty() => unit,
+            _ => return s,
+        };
+        let trimmed = s.trim_end();
+        let start = trimmed.len().saturating_sub(unit.len());
+        match trimmed.get(start..) {
+            Some(end) if end.eq_ignore_ascii_case(unit) => trimmed[..start].trim_end(),
+            _ => s,
+        }
+    }
+
+    /// Prompts the field once, using the given prefix.
+    ///
+    /// It checks the `line_brk` specification. If it is on `true`, the suffix is displayed
+    /// on a separate line, thus it will only display the suffix. Otherwise, it prints out
+    /// the whole message with the suffix.
+    fn prompt_once<R: BufRead, W: Write, T: FromStr>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        fmt: &Format<'_>,
+        opt: bool,
+    ) -> MenuResult<Option<T>> {
+        log_event!(debug, "prompting the written field `{}`", self.msg);
+        let s = self.prompt_line(stream, fmt, opt)?;
+        let out = self.parse_input(&s);
+        if out.is_some() {
+            log_event!(
+                info,
+                "accepted a value for the written field `{}`",
+                self.msg
+            );
+        }
+
+        // The field is prompted again, so we explain why and suggest the closest candidate, if any.
+        if out.is_none() && !opt {
+            if self.strict && !s.is_empty() {
+                let fmt = stream.output_format(fmt);
+                let error = tr(
+                    "ezmenulib.incorrect",
+                    "'{value}' is not a correct value",
+                    &[("value", &s)],
+                );
+                writeln!(stream, "{}", fmt.style.error(error))?;
+            }
+            if let Some(candidate) = closest(self.strip_unit(&s), self.suggestions) {
+                let fmt = stream.output_format(fmt);
+                let suggestion = tr(
+                    "ezmenulib.suggestion",
+                    "did you mean '{value}'?",
+                    &[("value", candidate)],
+                );
+                writeln!(stream, "{}", fmt.style.error(suggestion))?;
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// Returns the value parsed from the input, or the default value if the input
+    /// is empty or incorrect.
+    ///
+    /// In [strict](Written::strict) mode, the default value is only used if the input is empty.
+    /// A typed default value of another type is never used (see [`Written::default_typed`]).
+    ///
+    /// # Panics
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub(crate) fn parse_input<T: FromStr>(&self, s: &str) -> Option<T> {
+        let default_output = |d: &str| -> T {
+            self.strip_unit(d)
+                .parse()
+                .unwrap_or_else(|_| default_failed::<T>(d))
+        };
+
+        let default = self
+            .default
+            .as_deref()
+            .filter(|_| self.check_default::<T>().is_ok());
+        if s.is_empty() {
+            return default.map(default_output);
+        }
+
+        let out = self.strip_unit(s).parse().ok();
+        match self.strict {
+            true => out,
+            false => out.or_else(|| default.map(default_output)),
+        }
+    }
+
+    /// Prompts the field and returns the input, or `None` if the input is incorrect,
+    /// using the given format.
+    ///
+    /// It uses the merged version between the format of the written field and the given format.
+    ///
+    /// It prompts the field once, and if the user entered a correct input,
+    /// it returns `Some(value)`, otherwise, it attempts to return the default value
+    /// (see [`Written::default_value`] or [`Written::default_env`]), and if there is no
+    /// default value, it returns `None`.
+    ///
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]).
+    ///
+    /// # Panics
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn optional_value_with<R, W, T>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        fmt: &Format<'_>,
+    ) -> MenuResult<Option<T>>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+    {
+        self.check_default::<T>()?;
+        let fmt = self.fmt.merged(fmt);
+        self.first_line(stream, &fmt, true)?;
+        self.prompt_once(stream, &fmt, true)
+    }
+
+    /// Prompts the field and returns the input, or `None` if the input is incorrect.
+    ///
+    /// It prompts the field once, and if the user entered a correct input,
+    /// it returns `Some(value)`, otherwise, it attempts to return the default value
+    /// (see [`Written::default_value`] or [`Written::default_env`]), and if there is no
+    /// default value, it returns `None`.
+    ///
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]).
+    ///
+    /// # Panics
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn optional_value<R, W, T>(&self, stream: &mut MenuStream<R, W>) -> MenuResult<Option<T>>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+    {
+        self.optional_value_with(stream, &self.fmt)
+    }
+
+    /// Prompts the field and returns the inputs as a `Vec<T>` until the given
+    /// constraint is applied to all the values, using `sep` to split the input
+    /// into the output values, and using the given format.
+    ///
+    /// It uses the merged version between the format of the written field and the given format.
+    ///
+    /// After checking and parsing the values provided by the user, it calls the `til` function.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Panics
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn many_values_until_with<R, W, T, S, F>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        sep: S,
+        til: F,
+        fmt: &Format<'_>,
+    ) -> MenuResult<Vec<T>>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+        S: AsRef<str>,
+        F: Fn(&T) -> bool,
+    {
+        fn inner_prompt_once<R: BufRead, W: Write, T: FromStr>(
+            w: &Written<'_>,
+            stream: &mut MenuStream<R, W>,
+            sep: &str,
+            fmt: &Format<'_>,
+        ) -> MenuResult<Option<Vec<T>>> {
+            let s = w.prompt_line(stream, fmt, false)?;
+            let parse = |s: &str| T::from_str(w.strip_unit(s));
+            let res: Result<Vec<T>, T::Err> = s.split(sep).map(parse).collect();
+
+            Ok(res.ok().or_else(|| {
+                let default = w.default.as_ref()?;
+                let res: Result<Vec<T>, T::Err> = default.split(sep).map(parse).collect();
+                Some(res.unwrap_or_else(|_| default_failed::<T>(default)))
+            }))
+        }
+
+        self.check_default::<T>()?;
+        let fmt = self.fmt.merged(fmt);
+        self.first_line(stream, &fmt, false)?;
+        let s = sep.as_ref();
+
+        // Loops while incorrect input.
+        let mut attempts = 0;
+        loop {
+            match inner_prompt_once(self, stream, s, &fmt)? {
+                Some(v) if v.iter().all(&til) => return Ok(v),
+                _ => attempt(self.max_attempts, self.backoff, &mut attempts)?,
+            }
+        }
+    }
+
+    /// Prompts the field and returns the inputs as a `Vec<T>` until the given
+    /// constraint is applied to all the values, using `sep` to split the input
+    /// into the output values.
+    ///
+    /// After checking and parsing the values provided by the user, it calls the `til` function.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Panics
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn many_values_until<R, W, T, S, F>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        sep: S,
+        til: F,
+    ) -> MenuResult<Vec<T>>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+        S: AsRef<str>,
+        F: Fn(&T) -> bool,
+    {
+        self.many_values_until_with(stream, sep, til, &self.fmt)
+    }
+
+    /// Prompts the field and returns the inputs as a `Vec<T>` using `sep` to split the input
+    /// into the output values, and using the given format.
+    ///
+    /// It uses the merged version between the format of the written field and the given format.
+    ///
+    /// After checking and parsing the values provided by the user, it calls the `til` function.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Panics
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn many_values_with<R, W, T, S>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        sep: S,
+        fmt: &Format<'_>,
+    ) -> MenuResult<Vec<T>>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+        S: AsRef<str>,
+    {
+        self.many_values_until_with(stream, sep, keep, fmt)
+    }
+
+    /// Prompts the field and returns the inputs as a `Vec<T>` using `sep` to split the input
+    /// into the output values.
+    ///
+    /// After checking and parsing the values provided by the user, it calls the `til` function.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Panics
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn many_values<R, W, T, S>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        sep: S,
+    ) -> MenuResult<Vec<T>>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+        S: AsRef<str>,
+    {
+        self.many_values_with(stream, sep, &self.fmt)
+    }
+
+    /// Prompts the field until the constraint is applied, using the given format.
+    ///
+    /// It uses the merged version between the format of the written field and the given format.
+    /// After checking and parsing the value provided by the user, it calls the `til` function.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Panic
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn prompt_until_with<R, W, T, F>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        til: F,
+        fmt: &Format<'a>,
+    ) -> MenuResult<T>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+        F: Fn(&T) -> bool,
+    {
+        self.check_default::<T>()?;
+        let fmt = self.fmt.merged(fmt);
+        self.first_line(stream, &fmt, false)?;
+
+        // Loops while incorrect input.
+        let mut attempts = 0;
+        loop {
+            match self.prompt_once(stream, &fmt, false)? {
+                Some(out) if til(&out) => return Ok(out),
+                _ => attempt(self.max_attempts, self.backoff, &mut attempts)?,
+            }
+        }
+    }
+
+    /// Prompts the field until the constraint is applied.
+    ///
+    /// After checking and parsing the value provided by the user, it calls the `til` function.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Panic
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn prompt_until<R, W, T, F>(&self, stream: &mut MenuStream<R, W>, til: F) -> MenuResult<T>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+        F: Fn(&T) -> bool,
+    {
+        self.prompt_until_with(stream, til, &self.fmt)
+    }
+
+    /// Prompts the field until the value is validated by the given function,
+    /// using the given format.
+    ///
+    /// It uses the merged version between the format of the written field and the given format.
+    /// After checking and parsing the value provided by the user, it calls the `validate` function.
+    /// If the latter returns an error, the error is printed out to the stream and the field
+    /// is prompted again.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Panic
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn prompt_validated_with<R, W, T, F, E>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        validate: F,
+        fmt: &Format<'a>,
+    ) -> MenuResult<T>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+        F: Fn(&T) -> Result<(), E>,
+        E: Display,
+    {
+        self.check_default::<T>()?;
+        let fmt = stream.output_format(&self.fmt.merged(fmt));
+        self.first_line(stream, &fmt, false)?;
+
+        // Loops while incorrect input.
+        let mut attempts = 0;
+        loop {
+            if let Some(out) = self.prompt_once(stream, &fmt, false)? {
+                match validate(&out) {
+                    Ok(()) => return Ok(out),
+                    Err(e) => writeln!(stream, "{}", fmt.style.error(e))?,
+                }
+            }
+            attempt(self.max_attempts, self.backoff, &mut attempts)?;
+        }
+    }
+
+    /// Prompts the field until the value is validated by the given function.
+    ///
+    /// After checking and parsing the value provided by the user, it calls the `validate` function.
+    /// If the latter returns an error, the error is printed out to the stream and the field
+    /// is prompted again.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let port: u16 = Written::from("Port").prompt_validated(
+    ///     &mut MenuStream::default(),
+    ///     |p: &u16| if *p >= 1024 { Ok(()) } else { Err("the port must be unprivileged") },
+    /// )?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Panic
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn prompt_validated<R, W, T, F, E>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        validate: F,
+    ) -> MenuResult<T>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+        F: Fn(&T) -> Result<(), E>,
+        E: Display,
+    {
+        self.prompt_validated_with(stream, validate, &self.fmt)
+    }
+
+    /// Prompts the field until the input is parsed by the given function, using the given format.
+    ///
+    /// It uses the merged version between the format of the written field and the given format.
+    /// The unit of the field is removed from the input before calling the `parse` function,
+    /// and the default value is parsed if the input is empty. If the function returns an error,
+    /// the error is printed out to the stream and the field is prompted again.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    pub fn prompt_parsed_with<R, W, T, F, E>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        parse: F,
+        fmt: &Format<'a>,
+    ) -> MenuResult<T>
+    where
+        R: BufRead,
+        W: Write,
+        F: Fn(&str) -> Result<T, E>,
+        E: Display,
+    {
+        let fmt = stream.output_format(&self.fmt.merged(fmt));
+        self.first_line(stream, &fmt, false)?;
+
+        // Loops while incorrect input.
+        let mut attempts = 0;
+        loop {
+            log_event!(debug, "prompting the written field `{}`", self.msg);
+            let s = self.prompt_line(stream, &fmt, false)?;
+            let input = match self.default.as_deref() {
+                Some(d) if s.is_empty() => d,
+                _ => &s,
+            };
+            match parse(self.strip_unit(input)) {
+                Ok(out) => {
+                    log_event!(
+                        info,
+                        "accepted a value for the written field `{}`",
+                        self.msg
+                    );
+                    return Ok(out);
+                }
+                Err(e) => writeln!(stream, "{}", fmt.style.error(e))?,
+            }
+            attempt(self.max_attempts, self.backoff, &mut attempts)?;
+        }
+    }
+
+    /// Prompts the field until the input is parsed by the given function.
+    ///
+    /// It allows to retrieve the values whose type does not implement [`FromStr`],
+    /// or with another syntax. The unit of the field is removed from the input before calling
+    /// the `parse` function, and the default value is parsed if the input is empty.
+    /// If the function returns an error, the error is printed out to the stream and the field
+    /// is prompted again.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ezmenulib::prelude::*;
+    /// # use std::time::Duration;
+    /// # fn main() -> MenuResult {
+    /// # let mut input = "1h30m\n".as_bytes();
+    /// # let mut output = Vec::new();
+    /// # let mut stream = MenuStream::with(&mut input, &mut output);
+    /// let timeout = Written::from("Timeout").prompt_parsed(&mut stream, |s| {
+    ///     let (h, m) = s.strip_suffix('m').and_then(|s| s.split_once('h')).ok_or("expected 1h30m")?;
+    ///     let mins = h.parse::<u64>().map_err(|_| "incorrect hours")? * 60
+    ///         + m.parse::<u64>().map_err(|_| "incorrect minutes")?;
+    ///     Ok::<_, &str>(Duration::from_secs(mins * 60))
+    /// })?;
+    /// assert_eq!(timeout, Duration::from_secs(5400));
+    /// # Ok(()) }
+    /// ```
+    pub fn prompt_parsed<R, W, T, F, E>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        parse: F,
+    ) -> MenuResult<T>
+    where
+        R: BufRead,
+        W: Write,
+        F: Fn(&str) -> Result<T, E>,
+        E: Display,
+    {
+        self.prompt_parsed_with(stream, parse, &self.fmt)
+    }
+
+    /// Prompts the field, using the given format.
+    ///
+    /// It uses the merged version between the format of the written field and the given format.
+    /// It prompts the field until the value provided by the user is correct, then parses it.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Panic
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn prompt_with<R, W, T>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        fmt: &Format<'a>,
+    ) -> MenuResult<T>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+    {
+        self.prompt_until_with(stream, keep, fmt)
+    }
+
+    /// Prompts the field.
+    ///
+    /// It prompts the field until the value provided by the user is correct, then parses it.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Panic
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn prompt<R, W, T>(&self, stream: &mut MenuStream<R, W>) -> MenuResult<T>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+    {
+        self.prompt_with(stream, &self.fmt)
+    }
+
+    /// Prompts the field and returns the input value, or the default value of the type
+    /// if the input is incorrect, using the given format.
+    ///
+    /// It uses the merged version between the format of the written field and the given format.
+    ///
+    /// It prompts the value once, and if the user entered a correct input, it returns the value,
+    /// otherwise, it attempts to return the default value (see [`Written::default_value`]
+    /// or [`Written::default_env`]), and if there is no default value, it returns
+    /// the [default](Default) implementation of `T`.
+    ///
+    /// # Panics
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn prompt_or_default_with<R, W, T>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        fmt: &Format<'_>,
+    ) -> T
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr + Default,
+    {
+        self.optional_value_with(stream, fmt)
+            .map(Option::unwrap_or_default)
+            .unwrap_or_default()
+    }
+
+    /// Prompts the field and returns the input value, or the default value of the type
+    /// if the input is incorrect.
+    ///
+    /// It prompts the value once, and if the user entered a correct input, it returns the value,
+    /// otherwise, it attempts to return the default value (see [`Written::default_value`]
+    /// or [`Written::default_env`]), and if there is no default value, it returns
+    /// the [default](Default) implementation of `T`.
+    ///
+    /// # Panics
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn prompt_or_default<R, W, T>(&self, stream: &mut MenuStream<R, W>) -> T
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr + Default,
+    {
+        self.prompt_or_default_with(stream, &self.fmt)
+    }
+}
+
+impl UsesFormat for Written<'_> {
+    fn get_format(&self) -> &Format<'_> {
+        &self.fmt
+    }
+}
@@ -0,0 +1,152 @@
+//! Module defining the translation of the messages displayed by the fields.
+//!
+//! A [`Localizer`] installed with the [`set_localizer`] function translates the messages
+//! of the fields with a [message key](crate::field::Written::i18n), and the strings
+//! displayed by the library, which are identified by the keys below:
+//!
+//! | Key | Default string |
+//! |-----|----------------|
+//! | `ezmenulib.unit` | `unit` |
+//! | `ezmenulib.example` | `example` |
+//! | `ezmenulib.default` | `default` |
+//! | `ezmenulib.optional` | `optional` |
+//! | `ezmenulib.help-default` | `Press Enter to use the default value.` |
+//! | `ezmenulib.help-skip` | `Press Enter to skip this field.` |
+//! | `ezmenulib.help-example` | `Enter a value like the example.` |
+//! | `ezmenulib.help-index` | `Enter the index of a value.` |
+//! | `ezmenulib.help-arrows` | `Select a value with the arrow keys, then press Enter.` |
+//! | `ezmenulib.incorrect` | `'{value}' is not a correct value` |
+//! | `ezmenulib.suggestion` | `did you mean '{value}'?` |
+//! | `ezmenulib.next-page` | `next page` |
+//! | `ezmenulib.prev-page` | `previous page` |
+//!
+//! If the localizer returns `None`, the default string is displayed. The labels
+//! customized by the [format](crate::field::Format::default_label) of a field
+//! are displayed as is.
+//!
+//! # Example
+//!
+//! ```
+//! use ezmenulib::{i18n, prelude::*};
+//!
+//! i18n::set_localizer(|key: &str, _: &[(&str, &str)]| match key {
+//!     "ezmenulib.default" => Some("défaut".to_owned()),
+//!     "app.age" => Some("Votre âge".to_owned()),
+//!     _ => None,
+//! });
+//!
+//! let age = Written::from("Your age").i18n("app.age").default_value("18");
+//! assert!(age.to_string().starts_with("--> Votre âge (défaut: 18)"));
+//! # i18n::clear_localizer();
+//! ```
+
+use std::borrow::Cow;
+use std::sync::RwLock;
+
+/// Defines how the messages displayed by the fields are translated.
+///
+/// It is implemented for the closures taking the key and the arguments of the message,
+/// and for the concurrent bundles of the [`fluent`](https://docs.rs/fluent/) crate
+/// with the `fluent` feature.
+pub trait Localizer: Send + Sync {
+    /// Returns the translation of the message identified by the given key,
+    /// or `None` to display the default string.
+    ///
+    /// The arguments are the values inserted in the message, like the incorrect value
+    /// entered by the user, with their name.
+    fn localize(&self, key: &str, args: &[(&str, &str)]) -> Option<String>;
+}
+
+impl<F> Localizer for F
+where
+    F: Fn(&str, &[(&str, &str)]) -> Option<String> + Send + Sync,
+{
+    fn localize(&self, key: &str, args: &[(&str, &str)]) -> Option<String> {
+        self(key, args)
+    }
+}
+
+/// Translates the messages with a [fluent bundle](fluent::concurrent::FluentBundle).
+///
+/// The part of the key before the first dot is the identifier of the message,
+/// and the part after is the name of its attribute, so the keys of the library
+/// are defined in a single message:
+///
+/// ```ftl
+/// ezmenulib =
+///     .default = défaut
+///     .optional = facultatif
+///     .incorrect = '{ $value }' n'est pas une valeur correcte
+/// ```
+#[cfg(feature = "fluent")]
+#[cfg_attr(nightly, doc(cfg(feature = "fluent")))]
+impl<R> Localizer for fluent::concurrent::FluentBundle<R>
+where
+    R: std::borrow::Borrow<fluent::FluentResource> + Send + Sync,
+{
+    fn localize(&self, key: &str, args: &[(&str, &str)]) -> Option<String> {
+        let (id, attr) = match key.split_once('.') {
+            Some((id, attr)) => (id, Some(attr)),
+            None => (key, None),
+        };
+        let msg = self.get_message(id)?;
+        let pattern = match attr {
+            Some(attr) => msg.get_attribute(attr)?.value(),
+            None => msg.value()?,
+        };
+
+        let mut fluent_args = fluent::FluentArgs::new();
+        for (name, value) in args {
+            fluent_args.set(*name, *value);
+        }
+        let mut errors = Vec::new();
+        let out = self.format_pattern(pattern, Some(&fluent_args), &mut errors);
+        errors.is_empty().then(|| out.into_owned())
+    }
+}
+
+static LOCALIZER: RwLock<Option<Box<dyn Localizer>>> = RwLock::new(None);
+
+/// Installs the localizer translating the messages displayed by the fields,
+/// replacing the previous one.
+pub fn set_localizer<L: Localizer + 'static>(localizer: L) {
+    let mut current = LOCALIZER.write().unwrap_or_else(|e| e.into_inner());
+    *current = Some(Box::new(localizer));
+}
+
+/// Removes the installed localizer, so the default strings are displayed.
+pub fn clear_localizer() {
+    let mut current = LOCALIZER.write().unwrap_or_else(|e| e.into_inner());
+    *current = None;
+}
+
+/// Returns the translation of the message identified by the given key,
+/// or the default string with the arguments inserted as `{name}`.
+pub(crate) fn tr<'s>(key: &str, default: &'s str, args: &[(&str, &str)]) -> Cow<'s, str> {
+    let current = LOCALIZER.read().unwrap_or_else(|e| e.into_inner());
+    if let Some(s) = current.as_ref().and_then(|l| l.localize(key, args)) {
+        return Cow::Owned(s);
+    }
+    args.iter()
+        .fold(Cow::Borrowed(default), |s, (name, value)| {
+            Cow::Owned(s.replace(&format!("{{{}}}", name), value))
+        })
+}
+
+/// Returns the translation of the given label of a format if it is the default label,
+/// or the label customized by the format.
+pub(crate) fn tr_label<'s>(key: &str, label: &'s str, default: &str) -> Cow<'s, str> {
+    match label == default {
+        true => tr(key, label, &[]),
+        false => Cow::Borrowed(label),
+    }
+}
+
+/// Returns the translation of the message of a field if it has a message key,
+/// or the message itself.
+pub(crate) fn tr_msg<'s>(key: Option<&str>, msg: &'s str) -> Cow<'s, str> {
+    match key {
+        Some(key) => tr(key, msg, &[]),
+        None => Cow::Borrowed(msg),
+    }
+}
@@ -24,13 +24,25 @@
 )]
 #![cfg_attr(nightly, feature(doc_cfg))]
 
+/// Emits a record with the given level if the `log` feature is enabled.
+///
+/// The values entered by the user are never logged, as they may be secrets.
+macro_rules! log_event {
+    ($lvl:ident, $($arg:tt)+) => {
+        #[cfg(feature = "log")]
+        ::log::$lvl!(target: "ezmenulib", $($arg)+);
+    };
+}
+
 #[cfg(all(feature = "tui", any(feature = "crossterm", feature = "termion")))]
 #[cfg_attr(nightly, doc(cfg(feature = "tui")))]
 pub mod tui;
 
 pub mod customs;
 pub mod field;
+pub mod i18n;
 pub mod menu;
+pub mod testing;
 
 mod utils;
 
@@ -40,10 +52,11 @@ pub mod prelude {
     pub use crate::menu::*;
 
     pub use crate::MenuError;
+    pub use crate::MenuErrorKind;
     pub use crate::MenuResult;
 }
 
-use crate::field::Format;
+use crate::field::{Format, IndexStyle, Style, Verbosity};
 use std::env::VarError;
 use std::error::Error;
 use std::fmt::{self, Debug, Display, Formatter};
@@ -57,6 +70,18 @@ pub(crate) const DEFAULT_FMT: Format<'static> = Format {
     line_brk: true,
     left_sur: "[",
     right_sur: "]",
+    max_label_width: None,
+    page_size: None,
+    style: Style::PLAIN,
+    verbosity: Verbosity::Normal,
+    indent: 0,
+    start_index: 1,
+    index_style: IndexStyle::Numeric,
+    optional_label: "optional",
+    default_label: "default",
+    example_label: "example",
+    show_example: true,
+    show_optional: true,
 };
 
 /// The error type used by the menu builder.
@@ -66,12 +91,76 @@ pub enum MenuError {
     IOError(io::Error),
     /// A parsing error for a value.
     Input,
+    /// The user cancelled the prompt, e.g. by pressing `Ctrl-D` or by closing the input stream.
+    ///
+    /// It is distinct from an incorrect input, so the caller can clean up gracefully.
+    Cancelled,
+    /// The user provided too many incorrect inputs
+    /// (see [`Written::max_attempts`](crate::field::Written::max_attempts)).
+    TooManyAttempts,
+    /// The user did not press any key before the timeout elapsed, and there is no default value.
+    ///
+    /// The timeouts are enabled with the `"crossterm"` feature, e.g. with `Written::timeout`.
+    Timeout,
     /// An environment variable error.
     EnvVar(String, VarError),
     /// An error occurred when formatting a field.
     Format(fmt::Error),
-    /// A custom error.
-    Other(Box<dyn Debug>),
+    /// A custom error, e.g. returned by a function mapped to a field.
+    ///
+    /// The error is kept as is, so its chain of sources can be reported.
+    Other(Box<dyn Error + Send + Sync>),
+}
+
+/// The kind of a [`MenuError`], regardless of the error it may contain.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MenuErrorKind {
+    /// See [`MenuError::IOError`].
+    IO,
+    /// See [`MenuError::Input`].
+    Input,
+    /// See [`MenuError::Cancelled`].
+    Cancelled,
+    /// See [`MenuError::TooManyAttempts`].
+    TooManyAttempts,
+    /// See [`MenuError::Timeout`].
+    Timeout,
+    /// See [`MenuError::EnvVar`].
+    EnvVar,
+    /// See [`MenuError::Format`].
+    Format,
+    /// See [`MenuError::Other`].
+    Other,
+}
+
+impl MenuError {
+    /// Returns the custom error wrapping the given error, keeping its chain of sources.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ezmenulib::prelude::*;
+    /// let e = "x".parse::<u8>().map_err(MenuError::other).unwrap_err();
+    /// assert_eq!(e.kind(), MenuErrorKind::Other);
+    /// ```
+    pub fn other<E: Error + Send + Sync + 'static>(e: E) -> Self {
+        Self::Other(Box::new(e))
+    }
+
+    /// Returns the kind of the error.
+    pub fn kind(&self) -> MenuErrorKind {
+        match self {
+            Self::IOError(_) => MenuErrorKind::IO,
+            Self::Input => MenuErrorKind::Input,
+            Self::Cancelled => MenuErrorKind::Cancelled,
+            Self::TooManyAttempts => MenuErrorKind::TooManyAttempts,
+            Self::Timeout => MenuErrorKind::Timeout,
+            Self::EnvVar(..) => MenuErrorKind::EnvVar,
+            Self::Format(_) => MenuErrorKind::Format,
+            Self::Other(_) => MenuErrorKind::Other,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -82,7 +171,21 @@ impl PartialEq for MenuError {
     }
 }
 
-impl Error for MenuError {}
+impl Error for MenuError {
+    /// Returns the source of the contained error, if any.
+    ///
+    /// The message of the contained error is already displayed by the menu error,
+    /// so it is not returned itself.
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::IOError(e) => e.source(),
+            Self::EnvVar(_, e) => e.source(),
+            Self::Format(e) => e.source(),
+            Self::Other(e) => e.source(),
+            _ => None,
+        }
+    }
+}
 
 impl Debug for MenuError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
@@ -97,12 +200,15 @@ impl Display for MenuError {
             match self {
                 Self::IOError(e) => format!("IO error: {}", e),
                 Self::Input => "an incorrect input has been provided".to_owned(),
+                Self::Cancelled => "the prompt has been cancelled by the user".to_owned(),
+                Self::TooManyAttempts => "too many incorrect inputs have been provided".to_owned(),
+                Self::Timeout => "no input has been provided in time".to_owned(),
                 Self::EnvVar(v, e) => format!(
                     "attempted to get a default value from the environment variable `{}`: {}",
                     v, e
                 ),
                 Self::Format(e) => format!("an error occurred while formatting a field: {:?}", e),
-                Self::Other(d) => format!("{:?}", d),
+                Self::Other(e) => e.to_string(),
             }
         ))
     }
@@ -118,14 +224,21 @@ impl From<io::Error> for MenuError {
 impl From<&'static str> for MenuError {
     #[inline]
     fn from(s: &'static str) -> Self {
-        Self::Other(Box::new(s))
+        Self::Other(s.into())
     }
 }
 
 impl From<String> for MenuError {
     #[inline]
     fn from(s: String) -> Self {
-        Self::Other(Box::new(s))
+        Self::Other(s.into())
+    }
+}
+
+impl From<Box<dyn Error + Send + Sync>> for MenuError {
+    #[inline]
+    fn from(e: Box<dyn Error + Send + Sync>) -> Self {
+        Self::Other(e)
     }
 }
 
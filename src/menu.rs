@@ -3,16 +3,32 @@
 #[cfg(test)]
 mod tests;
 
+mod state;
 mod stream;
+mod usage;
+mod wizard;
 
+use crate::customs::MenuBool;
+pub use crate::menu::state::MenuState;
+pub(crate) use crate::menu::state::Visit;
 pub use crate::menu::stream::{MenuStream, Mutable};
+pub use crate::menu::usage::Usage;
+pub use crate::menu::wizard::{Aborted, Flow, Wizard};
 use crate::prelude::*;
-use crate::utils::{check_fields, select, Depth};
+use crate::utils::{check_fields, keep, logged, navigate, select, truncate, Choice, Depth, Pages};
+#[cfg(feature = "crossterm")]
+use crate::utils::{select_arrows, Wait};
 
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::fmt::{self, Display, Formatter};
 use std::io::{BufRead, BufReader, Stdin, Stdout, Write};
+use std::mem;
 use std::ops::{Deref, DerefMut};
+use std::path::PathBuf;
 use std::str::FromStr;
+#[cfg(feature = "crossterm")]
+use std::time::Duration;
 
 /// The default input stream used by a menu, using the standard input stream.
 pub type In = BufReader<Stdin>;
@@ -63,6 +79,33 @@ pub trait FromMutable<'a, S: 'a, Arg>: Sized {
     }
 }
 
+/// Used to run a menu through a trait object.
+///
+/// It is implemented by the [`RawMenu`] type, and by the `TuiMenu` type with the `tui` feature.
+/// This is useful for an application holding different menus, for example
+/// as `Vec<Box<dyn DynMenu>>`, to run whichever the user picks.
+///
+/// # Example
+///
+/// ```no_run
+/// use ezmenulib::prelude::*;
+///
+/// # fn main() -> MenuResult {
+/// let mut menus: Vec<Box<dyn DynMenu>> = vec![
+///     Box::new(RawMenu::from(&[("Play", Kind::Quit)])),
+///     Box::new(RawMenu::from(&[("Settings", Kind::Quit)])),
+/// ];
+///
+/// for menu in menus.iter_mut() {
+///     menu.run()?;
+/// }
+/// # Ok(()) }
+/// ```
+pub trait DynMenu {
+    /// Runs the menu.
+    fn run(&mut self) -> MenuResult;
+}
+
 /// Container used to handle the [stream](MenuStream) and the global [format](Format).
 ///
 /// The `R` type parameter represents its reader type,
@@ -125,8 +168,20 @@ pub struct Values<'a, R = In, W = Out> {
     /// The global format of the container.
     pub fmt: Format<'a>,
     stream: Mutable<'a, MenuStream<'a, R, W>>,
+    answers: HashMap<String, String>,
+    show_answers: bool,
 }
 
+/// The previous name of the [`Values`] struct.
+///
+/// The container does not own the fields anymore: they are passed to its methods
+/// to retrieve their value, e.g. [`Values::written`] or [`Values::selected`].
+#[deprecated(
+    since = "0.3.0",
+    note = "renamed to `Values`, which is given the fields to prompt"
+)]
+pub type ValueMenu<'a, R = In, W = Out> = Values<'a, R, W>;
+
 /// Returns the default container, which corresponds to the
 /// [default format](Format::default) and the [owned default stream](MenuStream::default).
 // Cannot use the derivable implementation of `Default`
@@ -139,6 +194,8 @@ impl Default for Values<'_> {
         Self {
             fmt: Format::default(),
             stream: Mutable::default(),
+            answers: HashMap::new(),
+            show_answers: false,
         }
     }
 }
@@ -170,7 +227,12 @@ impl<'a> From<Format<'a>> for Values<'a> {
 
 impl<'a, R, W> FromMutable<'a, MenuStream<'a, R, W>, Format<'a>> for Values<'a, R, W> {
     fn new(stream: Mutable<'a, MenuStream<'a, R, W>>, fmt: Format<'a>) -> Self {
-        Self { fmt, stream }
+        Self {
+            fmt,
+            stream,
+            answers: HashMap::new(),
+            show_answers: false,
+        }
     }
 }
 
@@ -184,6 +246,104 @@ impl<'a, R, W> Values<'a, R, W> {
         self.fmt = fmt;
         self
     }
+
+    /// Defines the global formatting from the given [theme](Theme).
+    pub fn theme<T: Theme>(self, theme: T) -> Self {
+        self.format(theme.format())
+    }
+
+    /// Returns the effective format of the given field after merging it with the global format,
+    /// with the level each specification comes from.
+    ///
+    /// Each line contains a specification, followed by `(field)` if it is defined
+    /// by the field, `(global)` if it is defined by the container, or `(default)`
+    /// if none of them define it. It is useful for debugging, e.g. to find out
+    /// why a custom suffix is not applied.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ezmenulib::prelude::*;
+    /// let values = Values::default().format(Format::suffix("$ "));
+    /// let explained = values.explain_format(&Written::from("Name").format(Format::prefix("* ")));
+    /// assert!(explained.contains("prefix: \"* \" (field)"));
+    /// assert!(explained.contains("suffix: \"$ \" (global)"));
+    /// assert!(explained.contains("chip: \" - \" (default)"));
+    /// ```
+    pub fn explain_format<F: UsesFormat>(&self, field: &F) -> String {
+        field.get_format().explain(&self.fmt)
+    }
+
+    /// Defines the amount of details displayed with the fields
+    /// ([`Verbosity::Normal`] by default).
+    ///
+    /// It overrides the [`Format::verbosity`] specification of the global format,
+    /// so it can be chosen at runtime, e.g. from a command-line flag.
+    pub fn verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.fmt.verbosity = verbosity;
+        self
+    }
+
+    /// Hides the examples of all the written fields, for a compact layout.
+    ///
+    /// It overrides the [`Format::show_example`] specification of the global format.
+    pub fn hide_examples(mut self) -> Self {
+        self.fmt.show_example = false;
+        self
+    }
+
+    /// Defines if the optional fields are marked with `(optional)` (`true` by default).
+    ///
+    /// It overrides the [`Format::show_optional`] specification of the global format.
+    pub fn show_optional_hints(mut self, show: bool) -> Self {
+        self.fmt.show_optional = show;
+        self
+    }
+
+    /// Defines the answers of the fields, mapped by their message.
+    ///
+    /// When retrieving a value, the container first checks if the field has an answer,
+    /// and only prompts the user if it has none. This is useful to use the menu
+    /// non-interactively, e.g. in a CI environment.
+    ///
+    /// The answer of a written field is parsed like an input, and the answer of
+    /// a selectable field corresponds to the message of the selected value.
+    /// If an answer is incorrect, the container does not prompt the field to the user,
+    /// and handles it as an incorrect input (for example, [`Values::written`]
+    /// returns a [`MenuError::Input`] error).
+    ///
+    /// An answer is used each time the field is prompted.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # use std::collections::HashMap;
+    /// # fn main() -> MenuResult {
+    /// let mut menu = Values::default().with_answers(HashMap::from([
+    ///     ("What is your name?".to_owned(), "Ahmad".to_owned()),
+    /// ]));
+    /// // Does not prompt the user.
+    /// let name: String = menu.written(&Written::from("What is your name?"))?;
+    /// // Prompts the user.
+    /// let age: u8 = menu.written(&Written::from("How old are you?"))?;
+    /// # Ok(()) }
+    /// ```
+    pub fn with_answers(mut self, answers: HashMap<String, String>) -> Self {
+        self.answers = answers;
+        self
+    }
+
+    /// Defines if the answers are displayed when they are used (`false` by default).
+    ///
+    /// Each answered field is then displayed as a confirmed line, with its message followed
+    /// by the answer, e.g. `--> What is your name?: Ahmad`. This is useful to resume
+    /// an interrupted form from the saved answers, while showing the user what has already
+    /// been answered (see [`Values::with_answers`]).
+    pub fn show_answers(mut self, show: bool) -> Self {
+        self.show_answers = show;
+        self
+    }
 }
 
 impl<'a, R, W> UsesMutable<MenuStream<'a, R, W>> for Values<'a, R, W> {
@@ -220,6 +380,9 @@ where
     ///
     /// See [`Selected::select`] function fore more information.
     pub fn selected<T, const N: usize>(&mut self, sel: Selected<'_, T, N>) -> MenuResult<T> {
+        if let Some(answer) = self.answer(sel.msg)? {
+            return sel.take_answer(&answer).ok_or(MenuError::Input);
+        }
         let fmt = sel.fmt.merged(&self.fmt);
         sel.format(fmt).select(self.stream.deref_mut())
     }
@@ -235,6 +398,9 @@ where
         &mut self,
         sel: Selected<'_, T, N>,
     ) -> MenuResult<Option<T>> {
+        if let Some(answer) = self.answer(sel.msg)? {
+            return Ok(sel.take_answer(&answer));
+        }
         let fmt = sel.fmt.merged(&self.fmt);
         sel.format(fmt).optional_select(self.stream.deref_mut())
     }
@@ -250,7 +416,10 @@ where
     where
         T: Default,
     {
-        let fmt = self.fmt.merged(&self.fmt);
+        if let Some(answer) = self.answer(sel.msg).ok().flatten() {
+            return sel.take_answer(&answer).unwrap_or_default();
+        }
+        let fmt = sel.fmt.merged(&self.fmt);
         sel.format(fmt).select_or_default(self.stream.deref_mut())
     }
 
@@ -269,9 +438,54 @@ where
     where
         T: FromStr,
     {
+        if let Some(res) = self.written_answer(written) {
+            return res;
+        }
         written.prompt_with(self.stream.deref_mut(), &self.fmt)
     }
 
+    /// Returns the absolute path of the file selected by the user among the entries
+    /// of the directories listed by the file picker.
+    ///
+    /// It merges the [format](Format) of the file picker with the global format of the container.
+    ///
+    /// See [`FilePicker::prompt`] for more information.
+    pub fn file(&mut self, picker: &FilePicker<'_>) -> MenuResult<PathBuf> {
+        picker.prompt_with(self.stream.deref_mut(), &self.fmt)
+    }
+
+    /// Returns the number retrieved by the spinner.
+    ///
+    /// It merges the [format](Format) of the spinner with the global format of the container.
+    ///
+    /// See [`Spinner::prompt`] for more information.
+    pub fn spinner(&mut self, spinner: &Spinner<'_>) -> MenuResult<i64> {
+        spinner.prompt_with(self.stream.deref_mut(), &self.fmt)
+    }
+
+    /// Returns the index of the row selected by the user in the table.
+    ///
+    /// It merges the [format](Format) of the table selection with the global format
+    /// of the container.
+    ///
+    /// See [`TableSelect::select`] for more information.
+    pub fn table(&mut self, table: &TableSelect<'_>) -> MenuResult<usize> {
+        table.select_with(self.stream.deref_mut(), &self.fmt)
+    }
+
+    /// Returns the secret loaded from the credential store of the system, or prompted
+    /// to the user if it is absent.
+    ///
+    /// It merges the [format](Format) of the written field of the secret with the global format
+    /// of the container.
+    ///
+    /// See [`Secret::prompt`] for more information.
+    #[cfg(feature = "keyring")]
+    #[cfg_attr(nightly, doc(cfg(feature = "keyring")))]
+    pub fn secret(&mut self, secret: &Secret<'_>) -> MenuResult<String> {
+        secret.prompt_with(self.stream.deref_mut(), &self.fmt)
+    }
+
     /// Returns the next value written by the user by prompting him the field
     /// until the given constraint is applied.
     ///
@@ -289,9 +503,58 @@ where
         T: FromStr,
         F: Fn(&T) -> bool,
     {
+        if let Some(res) = self.written_answer(written) {
+            return res.and_then(|t| til(&t).then_some(t).ok_or(MenuError::Input));
+        }
         written.prompt_until_with(self.stream.deref_mut(), til, &self.fmt)
     }
 
+    /// Returns the next value written by the user by prompting him the field
+    /// until the value is validated by the given function.
+    ///
+    /// It merges the [format](Format) of the field with the global format of the container.
+    /// The merge saves the custom formatting specification of the written field.
+    ///
+    /// See [`Written::prompt_validated`] for more information.
+    ///
+    /// # Panic
+    ///
+    /// If the given written field has an incorrect default value,
+    /// this function will panic at runtime.
+    pub fn written_validated<T, F, E>(
+        &mut self,
+        written: &Written<'_>,
+        validate: F,
+    ) -> MenuResult<T>
+    where
+        T: FromStr,
+        F: Fn(&T) -> Result<(), E>,
+        E: Display,
+    {
+        if let Some(res) = self.written_answer(written) {
+            return res.and_then(|t| validate(&t).map(|_| t).map_err(|e| e.to_string().into()));
+        }
+        written.prompt_validated_with(self.stream.deref_mut(), validate, &self.fmt)
+    }
+
+    /// Returns the next value written by the user by prompting him the field
+    /// until the input is parsed by the given function.
+    ///
+    /// It merges the [format](Format) of the field with the global format of the container.
+    /// The merge saves the custom formatting specification of the written field.
+    ///
+    /// See [`Written::prompt_parsed`] for more information.
+    pub fn written_parsed<T, F, E>(&mut self, written: &Written<'_>, parse: F) -> MenuResult<T>
+    where
+        F: Fn(&str) -> Result<T, E>,
+        E: Display,
+    {
+        if let Some(answer) = self.answer(written.msg)? {
+            return parse(written.strip_unit(&answer)).map_err(|e| e.to_string().into());
+        }
+        written.prompt_parsed_with(self.stream.deref_mut(), parse, &self.fmt)
+    }
+
     /// Returns the next value written by the user wrapped as `Some(value)`
     /// if the input is correct, else `None`.
     ///
@@ -308,6 +571,9 @@ where
     where
         T: FromStr,
     {
+        if let Some(res) = self.written_answer(written) {
+            return Ok(res.ok());
+        }
         written.optional_value_with(self.stream.deref_mut(), &self.fmt)
     }
 
@@ -334,6 +600,15 @@ where
         S: AsRef<str>,
         F: Fn(&T) -> bool,
     {
+        if let Some(answer) = self.answer(written.msg)? {
+            return answer
+                .split(sep.as_ref())
+                .map(|s| match written.strip_unit(s).parse() {
+                    Ok(t) if til(&t) => Ok(t),
+                    _ => Err(MenuError::Input),
+                })
+                .collect();
+        }
         written.many_values_until_with(self.stream.deref_mut(), sep, til, &self.fmt)
     }
 
@@ -354,7 +629,7 @@ where
         T: FromStr,
         S: AsRef<str>,
     {
-        written.many_values_with(self.stream.deref_mut(), sep, &self.fmt)
+        self.many_written_until(written, sep, keep)
     }
 
     /// Returns the next value written by the user, or the default value of the
@@ -373,8 +648,84 @@ where
     where
         T: FromStr + Default,
     {
+        if let Some(res) = self.written_answer(written) {
+            return res.unwrap_or_default();
+        }
         written.prompt_or_default_with(self.stream.deref_mut(), &self.fmt)
     }
+
+    /// Returns the values retrieved by the given function, called repeatedly
+    /// while the user answers yes to the given written field.
+    ///
+    /// The function is called at least once. After each call, the written field is prompted
+    /// to ask the user if he wants to add another value. The answer is parsed as a [`MenuBool`].
+    ///
+    /// This is useful to retrieve many structured values, like a list of people
+    /// with their name and their age.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let mut menu = Values::default();
+    /// let people: Vec<(String, u8)> = menu.repeated(
+    ///     &Written::from("Add another person?").default_value("no"),
+    ///     |menu| Ok((
+    ///         menu.written(&Written::from("Name"))?,
+    ///         menu.written(&Written::from("Age"))?,
+    ///     )),
+    /// )?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Panic
+    ///
+    /// If the given written field has an incorrect default value,
+    /// this function will panic at runtime.
+    pub fn repeated<T, F>(&mut self, another: &Written<'_>, mut f: F) -> MenuResult<Vec<T>>
+    where
+        F: FnMut(&mut Self) -> MenuResult<T>,
+    {
+        let mut out = vec![f(self)?];
+        while *self.written::<MenuBool>(another)? {
+            out.push(f(self)?);
+        }
+        Ok(out)
+    }
+
+    /// Returns the answer of the field with the given message, if it has one.
+    ///
+    /// The answer is displayed as a confirmed line if the container shows the answers.
+    fn answer(&mut self, msg: &str) -> MenuResult<Option<String>> {
+        let answer = match self.answers.get(msg) {
+            Some(answer) => answer.clone(),
+            None => return Ok(None),
+        };
+        if self.show_answers {
+            let fmt = self.stream.output_format(&self.fmt);
+            writeln!(
+                self.stream,
+                "{}{msg}: {answer}",
+                fmt.style.prefix(fmt.prefix)
+            )?;
+        }
+        Ok(Some(answer))
+    }
+
+    /// Returns the answer of the written field parsed to `T`, if it has one.
+    fn written_answer<T: FromStr>(&mut self, written: &Written<'_>) -> Option<MenuResult<T>> {
+        let answer = match self.answer(written.msg) {
+            Ok(answer) => answer?,
+            Err(e) => return Some(Err(e)),
+        };
+        Some(
+            written
+                .strip_unit(&answer)
+                .parse()
+                .map_err(|_| MenuError::Input),
+        )
+    }
 }
 
 /// Defines a menu, with a title, the fields, and the reader and writer types.
@@ -414,6 +765,16 @@ pub struct RawMenu<'a, R = In, W = Out> {
     fields: Fields<'a, R, W>,
     stream: Mutable<'a, MenuStream<'a, R, W>>,
     once: bool,
+    usage: Option<Usage>,
+    hotkeys: HashMap<String, char>,
+    descriptions: HashMap<String, &'a str>,
+    zero: Option<Field<'a, R, W>>,
+    clear: bool,
+    state: MenuState,
+    #[cfg(feature = "crossterm")]
+    refresh: Option<Duration>,
+    #[cfg(feature = "crossterm")]
+    timeout: Option<Duration>,
 }
 
 impl<'a, R, W> UsesMutable<MenuStream<'a, R, W>> for RawMenu<'a, R, W> {
@@ -446,7 +807,13 @@ impl<R, W> Display for RawMenu<'_, R, W> {
         // Fields
         // The chip representation is managed by the field itself.
         for (i, field) in self.fields.iter().enumerate() {
-            writeln!(f, "{}{}{}", i + 1, self.fmt.chip, field.0)?;
+            writeln!(
+                f,
+                "{}{}{}",
+                i + 1,
+                self.fmt.chip,
+                truncate(field.0, self.fmt.max_label_width)
+            )?;
         }
 
         Ok(())
@@ -475,6 +842,16 @@ impl<'a, R, W> FromMutable<'a, MenuStream<'a, R, W>, Fields<'a, R, W>> for RawMe
             fields,
             stream,
             once: false,
+            usage: None,
+            hotkeys: HashMap::new(),
+            descriptions: HashMap::new(),
+            zero: None,
+            clear: false,
+            state: MenuState::new(),
+            #[cfg(feature = "crossterm")]
+            refresh: None,
+            #[cfg(feature = "crossterm")]
+            timeout: None,
         }
     }
 }
@@ -486,6 +863,11 @@ impl<'a, R, W> RawMenu<'a, R, W> {
         self
     }
 
+    /// Defines the global formatting from the given [theme](Theme).
+    pub fn theme<T: Theme>(self, theme: T) -> Self {
+        self.format(theme.format())
+    }
+
     /// Defines the title of the menu, which corresponds to the string slice displayed
     /// at the top when running the menu.
     pub fn title(mut self, title: &'a str) -> Self {
@@ -499,6 +881,200 @@ impl<'a, R, W> RawMenu<'a, R, W> {
         self.once = once;
         self
     }
+
+    /// Defines the counters of the selected fields of the menu.
+    ///
+    /// See [`Usage`] for more information.
+    pub fn usage(mut self, usage: Usage) -> Self {
+        self.usage = Some(usage);
+        self
+    }
+
+    /// Returns the counters of the selected fields of the menu, if any.
+    pub fn get_usage(&self) -> Option<&Usage> {
+        self.usage.as_ref()
+    }
+
+    /// Assigns a shortcut char to a field, so the user can select it by typing the char
+    /// instead of its index.
+    ///
+    /// The field is identified by the messages of its parent menus and its own message,
+    /// separated by `/`, like with the [`Usage`] counters. The shortcut is displayed
+    /// instead of the index of the field, but the index is still accepted.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// RawMenu::from(&[
+    ///     ("Settings", Kind::Parent(&[("Go back", Kind::Back(1))])),
+    ///     ("Quit", Kind::Quit),
+    /// ])
+    /// .hotkey("Quit", 'q')
+    /// .hotkey("Settings/Go back", 'b')
+    /// .run()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn hotkey<K: Into<String>>(mut self, key: K, hotkey: char) -> Self {
+        self.hotkeys.insert(key.into(), hotkey);
+        self
+    }
+
+    /// Assigns a long description to a menu, displayed when the user enters `?`
+    /// instead of selecting a field.
+    ///
+    /// The menu is identified by the messages of its parent fields, separated by `/`,
+    /// like with the [hotkeys](RawMenu::hotkey). The top-level menu is identified
+    /// by an empty string. The `?` input is ignored by the menus without description.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// RawMenu::from(&[
+    ///     ("Settings", Kind::Parent(&[("Go back", Kind::Back(1))])),
+    ///     ("Quit", Kind::Quit),
+    /// ])
+    /// .description("", "Manages the projects of the current workspace.")
+    /// .description("Settings", "Edits the configuration, saved on exit.")
+    /// .run()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn description<K: Into<String>>(mut self, key: K, desc: &'a str) -> Self {
+        self.descriptions.insert(key.into(), desc);
+        self
+    }
+
+    /// Defines if the terminal is cleared before each render of the menu (`false` by default).
+    ///
+    /// This way, the menu is displayed like a stable screen instead of an ever-growing
+    /// scrollback. The output of the mapped functions is cleared as well, so their result
+    /// should be posted with [`MenuStream::notify`] to stay visible above the menu.
+    ///
+    /// The screen is only cleared if the stream is [colored](MenuStream::colored),
+    /// which is the case of the default stream if the standard output is a terminal,
+    /// so no escape code is written to the redirected outputs.
+    pub fn clear_between(mut self, clear: bool) -> Self {
+        self.clear = clear;
+        self
+    }
+
+    /// Defines the submenu displayed first when running the menu, e.g. the submenu
+    /// saved by the previous run of the program.
+    ///
+    /// The parent fields of the submenu are entered without prompting the user,
+    /// and going back displays their parent menus as usual. If a field of the path
+    /// is not a parent field anymore, the menu stops at its parent menu.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # use std::fs;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let state = fs::read_to_string(".menu_state").unwrap_or_default().parse()?;
+    /// let mut menu = RawMenu::from(&[
+    ///     ("Settings", Kind::Parent(&[("Reset", Kind::Quit), ("Back", Kind::Back(1))])),
+    ///     ("Quit", Kind::Quit),
+    /// ])
+    /// .restore_state(state);
+    /// menu.run()?;
+    /// fs::write(".menu_state", menu.save_state().to_string())?;
+    /// # Ok(()) }
+    /// ```
+    pub fn restore_state(mut self, state: MenuState) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Returns the submenu displayed last by the previous run of the menu,
+    /// or the state given to [`RawMenu::restore_state`] if it has not run yet.
+    pub fn save_state(&self) -> MenuState {
+        self.state.clone()
+    }
+
+    /// Defines the interval at which the menu is displayed again while the user
+    /// does not press any key.
+    ///
+    /// The fields of the [dynamic menus](Kind::Dynamic) are generated again at each refresh,
+    /// so the menu can display a live status, like a simple dashboard. The highlighted
+    /// field stays the same.
+    ///
+    /// It only applies if the user selects the fields with the [arrow keys](MenuStream::arrows),
+    /// as the inputs are otherwise read line by line. The labels of the fields cannot be changed
+    /// by a callback: the live fields must be generated by a dynamic menu.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # use std::time::{Duration, SystemTime};
+    /// # fn main() -> MenuResult {
+    /// let status = |_: &mut MenuStream| {
+    ///     let now = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap();
+    ///     vec![
+    ///         (format!("Uptime: {}s", now.as_secs()), Kind::Back(0)),
+    ///         ("Go back".to_owned(), Kind::Back(1)),
+    ///     ]
+    /// };
+    ///
+    /// RawMenu::owned(
+    ///     MenuStream::default().arrows(true),
+    ///     &[("Status", Kind::Dynamic(&status)), ("Quit", Kind::Quit)],
+    /// )
+    /// .refresh(Duration::from_secs(1))
+    /// .run()?;
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "crossterm")]
+    #[cfg_attr(nightly, doc(cfg(feature = "crossterm")))]
+    pub fn refresh(mut self, interval: Duration) -> Self {
+        self.refresh = Some(interval);
+        self
+    }
+
+    /// Defines the time the user has to press a key, each time the menu is run.
+    ///
+    /// If the user does not press any key in time, running the menu returns
+    /// a [`MenuError::Timeout`] error, e.g. to run a default action in an unattended boot menu.
+    /// The timeout stops once the user pressed a key, and it is kept across the refreshes
+    /// (see [`RawMenu::refresh`]).
+    ///
+    /// It only applies if the user selects the fields with the [arrow keys](MenuStream::arrows),
+    /// as the blocking reads of the [`BufRead`] reader of the stream cannot be interrupted.
+    #[cfg(feature = "crossterm")]
+    #[cfg_attr(nightly, doc(cfg(feature = "crossterm")))]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Reserves the `0` index for the given field at every level of the menu.
+    ///
+    /// The field is displayed after the fields of each menu and sub-menu, so the authors
+    /// of the menu do not need to add a field to go back to every sub-menu.
+    /// The [kind](Kind) of the field defines its behavior, and is usually
+    /// `Kind::Back(1)` or `Kind::Quit`. For the top-level menu, going back closes the menu.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// RawMenu::from(&[
+    ///     ("Settings", Kind::Parent(&[("Reset", Kind::Map(&|_| Ok(())))])),
+    ///     ("Deploy", Kind::Map(&|_| Ok(()))),
+    /// ])
+    /// .zero("Back", Kind::Back(1))
+    /// .run()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn zero(mut self, msg: &'a str, kind: Kind<'a, R, W>) -> Self {
+        self.zero = Some((msg, kind));
+        self
+    }
 }
 
 impl<R, W> RawMenu<'_, R, W>
@@ -513,17 +1089,82 @@ where
     /// It prints to the stream the fields next to their indexes, then asks the user to
     /// select a field. Then, it runs the corresponding procedure
     /// matching the selected field [kind](Kind).
+    ///
+    /// If a field returns a value (see [`Kind::Value`]), the menu is closed
+    /// and the value is discarded.
     pub fn run(&mut self) -> MenuResult {
-        run_with(
+        self.run_depth().map(|_| ())
+    }
+
+    /// Runs the menu, and returns the first value returned by a field, if any.
+    ///
+    /// It behaves like the [`RawMenu::run`] function, but when a field returns a value
+    /// (see [`Kind::Value`]), the value is propagated through the nested menus to the top,
+    /// and the menu is closed. If the menu is closed without any value, it returns `None`.
+    ///
+    /// It returns an error if the value does not have the `T` type.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let port: Option<u16> = RawMenu::from(&[
+    ///     ("HTTP", Kind::Value(&|_| Ok(Some(Box::new(80u16))))),
+    ///     ("HTTPS", Kind::Value(&|_| Ok(Some(Box::new(443u16))))),
+    ///     ("Cancel", Kind::Quit),
+    /// ])
+    /// .run_for()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn run_for<T: 'static>(&mut self) -> MenuResult<Option<T>> {
+        match self.run_depth()? {
+            Depth::Output(out) => out
+                .downcast()
+                .map(|out| Some(*out))
+                .map_err(|_| "the value returned by the field has an incorrect type".into()),
+            _ => Ok(None),
+        }
+    }
+
+    fn run_depth(&mut self) -> MenuResult<Depth> {
+        let fmt = self.stream.output_format(&self.fmt);
+        let mut visit = Visit::new(&self.state);
+        let depth = run_with(
             &mut RunParams {
                 stream: self.stream.deref_mut(),
-                fmt: &self.fmt,
+                fmt: &fmt,
                 once: self.once,
+                usage: self.usage.as_mut(),
+                hotkeys: &self.hotkeys,
+                descriptions: &self.descriptions,
+                zero: self.zero.as_ref(),
+                path: Vec::new(),
+                visit: &mut visit,
+                clear: self.clear,
+                #[cfg(feature = "crossterm")]
+                wait: Wait {
+                    refresh: self.refresh,
+                    ..Wait::timeout(self.timeout)
+                },
+                #[cfg(feature = "crossterm")]
+                highlight: 0,
             },
             self.title,
             self.fields,
-        )
-        .map(|_| ())
+        );
+        self.state = visit.state();
+        depth
+    }
+}
+
+impl<R, W> DynMenu for RawMenu<'_, R, W>
+where
+    R: BufRead,
+    W: Write,
+{
+    fn run(&mut self) -> MenuResult {
+        RawMenu::run(self)
     }
 }
 
@@ -531,61 +1172,416 @@ where
 /// at any state of the menu (any depth of the `run_with` recursive function).
 struct RunParams<'a, 'b: 'a, R, W> {
     stream: &'a mut MenuStream<'b, R, W>,
-    fmt: &'a Format<'b>,
+    fmt: &'a Format<'a>,
     once: bool,
+    usage: Option<&'a mut Usage>,
+    hotkeys: &'a HashMap<String, char>,
+    descriptions: &'a HashMap<String, &'b str>,
+    zero: Option<&'a Field<'b, R, W>>,
+    // The messages of the parent menus of the current menu.
+    path: Vec<String>,
+    visit: &'a mut Visit,
+    clear: bool,
+    #[cfg(feature = "crossterm")]
+    wait: Wait,
+    // The field highlighted when the current menu is displayed again after a refresh.
+    #[cfg(feature = "crossterm")]
+    highlight: usize,
+}
+
+impl<R, W> RunParams<'_, '_, R, W> {
+    /// Returns the key identifying the field with the given message in the current menu,
+    /// used by the [`Usage`] counters.
+    fn key(&self, msg: &str) -> String {
+        let mut key = String::new();
+        for parent in &self.path {
+            key.push_str(parent);
+            key.push('/');
+        }
+        key.push_str(msg);
+        key
+    }
+}
+
+/// Prints out the description of the current menu, if any.
+fn show_description<R, W: Write>(params: &mut RunParams<R, W>) -> MenuResult {
+    if let Some(desc) = params.descriptions.get(&params.path.join("/")) {
+        writeln!(params.stream, "{desc}")?;
+    }
+    Ok(())
 }
 
 /// Prints out the menu to the terminal.
 fn show_menu<R, W: Write>(
     params: &mut RunParams<R, W>,
     msg: Option<&str>,
-    fields: Fields<R, W>,
+    entries: &Entries,
+    pages: &Pages,
 ) -> MenuResult {
-    // Title of current selective menu.
-    if let Some(s) = msg {
-        writeln!(params.stream, "{}{s}", params.fmt.prefix)?;
+    clear_screen(params)?;
+    show_notices(params)?;
+    show_title(params, msg)?;
+
+    // Fields of the current page of the selective menu.
+    let fmt = params.fmt;
+    for i in pages.range() {
+        let index = match entries.hotkeys[i] {
+            Some(c) => fmt.surround(c),
+            None => fmt.index(i),
+        };
+        show_entry(params, &index, &entries.labels[i])?;
+    }
+    if let Some((msg, _)) = params.zero {
+        show_entry(params, &fmt.surround(0), msg)?;
+    }
+
+    pages.fmt_controls(params.stream, params.fmt)?;
+    Ok(())
+}
+
+/// Clears the terminal before rendering the menu, if it is enabled and the stream is colored.
+fn clear_screen<R, W: Write>(params: &mut RunParams<R, W>) -> MenuResult {
+    if !params.clear || !params.stream.is_colored() {
+        return Ok(());
+    }
+
+    #[cfg(feature = "crossterm")]
+    {
+        use crossterm::{
+            cursor::MoveTo,
+            queue,
+            terminal::{Clear, ClearType},
+        };
+        queue!(params.stream, Clear(ClearType::All), MoveTo(0, 0))?;
     }
+    #[cfg(not(feature = "crossterm"))]
+    write!(params.stream, "\x1b[2J\x1b[H")?;
+    Ok(())
+}
 
-    // Fields of current selective menu.
-    for (i, (field_msg, _)) in (1..=fields.len()).zip(fields.iter()) {
+/// Prints out the status messages posted by the mapped functions since the last render.
+fn show_notices<R, W: Write>(params: &mut RunParams<R, W>) -> MenuResult {
+    for notice in params.stream.take_notices() {
+        match notice.error {
+            true => writeln!(params.stream, "{}", params.fmt.style.error(notice.msg))?,
+            false => writeln!(params.stream, "{}", notice.msg)?,
+        }
+    }
+    Ok(())
+}
+
+/// Prints out the title of the current selective menu, if any.
+fn show_title<R, W: Write>(params: &mut RunParams<R, W>, msg: Option<&str>) -> MenuResult {
+    if let Some(s) = msg {
         writeln!(
             params.stream,
-            "{}{i}{}{}{field_msg}",
-            params.fmt.left_sur, params.fmt.right_sur, params.fmt.chip
+            "{}{s}",
+            params.fmt.style.prefix(params.fmt.prefix)
         )?;
     }
+    Ok(())
+}
 
+/// Prints out a field of the menu next to its index or its hotkey, already surrounded.
+fn show_entry<R, W: Write>(params: &mut RunParams<R, W>, index: &str, label: &str) -> MenuResult {
+    writeln!(
+        params.stream,
+        "{}{}{}",
+        index,
+        params.fmt.chip,
+        truncate(label, params.fmt.max_label_width),
+    )?;
     Ok(())
 }
 
-/// Handles the field selected by the user.
+/// Handles the field selected by the user, at the given index among the fields
+/// of the current menu.
 fn handle_field<R: BufRead, W: Write>(
     params: &mut RunParams<R, W>,
+    index: usize,
     msg: &str,
     kind: &Kind<R, W>,
 ) -> MenuResult<Depth> {
     use Depth::*;
 
+    /// Returns the depth of the parent menu from the depth returned by its sub-menu.
+    fn parent_depth(depth: Depth) -> Depth {
+        match depth {
+            Current | Back(0) => Current,
+            Back(i) => Back(i - 1),
+            depth => depth,
+        }
+    }
+
     Ok(match kind {
         Kind::Map(f) => {
-            f(params.stream)?;
+            logged(f(params.stream), msg)?;
             if params.once {
                 Quit
             } else {
                 Current
             }
         }
-        Kind::Parent(fields) => match run_with(params, Some(msg), fields)? {
-            Current | Back(0) => Current,
-            Quit => Quit,
-            Back(i) => Back(i - 1),
+        Kind::Value(f) => match logged(f(params.stream), msg)? {
+            Some(out) => Output(out),
+            None if params.once => Quit,
+            None => Current,
         },
+        Kind::MapNavigate(f) => {
+            let nav = logged(f(params.stream), msg)?;
+            navigate(nav, &params.path, params.once)?
+        }
+        Kind::Parent(fields) => {
+            params.path.push(msg.to_owned());
+            params.visit.enter(index);
+            let depth = run_with(params, Some(msg), fields);
+            params.visit.leave();
+            params.path.pop();
+            parent_depth(depth?)
+        }
+        Kind::ParentTitled(title, fields) => {
+            params.path.push(msg.to_owned());
+            params.visit.enter(index);
+            let depth = run_with(params, Some(title), fields);
+            params.visit.leave();
+            params.path.pop();
+            parent_depth(depth?)
+        }
+        Kind::ParentWith(fmt, fields) => {
+            let fmt = params.stream.output_format(&fmt.merged(params.fmt));
+            // The parameters of the sub-menu borrow the ones of the current menu.
+            let mut sub = RunParams {
+                stream: &mut *params.stream,
+                fmt: &fmt,
+                once: params.once,
+                usage: params.usage.as_deref_mut(),
+                hotkeys: params.hotkeys,
+                descriptions: params.descriptions,
+                zero: params.zero,
+                path: mem::take(&mut params.path),
+                visit: &mut *params.visit,
+                clear: params.clear,
+                #[cfg(feature = "crossterm")]
+                wait: params.wait,
+                #[cfg(feature = "crossterm")]
+                highlight: 0,
+            };
+            sub.path.push(msg.to_owned());
+            sub.visit.enter(index);
+            let depth = run_with(&mut sub, Some(msg), fields);
+            sub.visit.leave();
+            sub.path.pop();
+            params.path = sub.path;
+            parent_depth(depth?)
+        }
+        Kind::Dynamic(f) => {
+            params.path.push(msg.to_owned());
+            params.visit.enter(index);
+            let depth = run_dynamic_with(params, Some(msg), f);
+            params.visit.leave();
+            params.path.pop();
+            parent_depth(depth?)
+        }
         Kind::Back(0) => Current,
         Kind::Back(i) => Back(i - 1),
         Kind::Quit => Quit,
     })
 }
 
+/// The fields of a menu, in the order they are displayed.
+struct Entries<'f> {
+    /// The indexes of the fields.
+    order: Vec<usize>,
+    labels: Vec<Cow<'f, str>>,
+    // The hotkeys are followed by the `0` hotkey of the zero field, if any.
+    hotkeys: Vec<Option<char>>,
+}
+
+/// Returns the fields in the order they are displayed, with their labels and hotkeys.
+///
+/// The fields are arranged according to the [`Usage`] counters, if any.
+fn arrange<'f, R, W, S: AsRef<str>>(
+    params: &RunParams<R, W>,
+    fields: &'f [(S, Kind<R, W>)],
+) -> Entries<'f> {
+    let keys: Vec<String> = fields
+        .iter()
+        .map(|(msg, _)| params.key(msg.as_ref()))
+        .collect();
+    let order = match params.usage {
+        Some(ref usage) => usage.order(keys.iter().map(String::as_str)),
+        None => (0..fields.len()).collect(),
+    };
+
+    let labels = order
+        .iter()
+        .map(|&i| {
+            let msg = fields[i].0.as_ref();
+            match params.usage {
+                Some(ref usage) if usage.is_annotated() => {
+                    format!("{} ({})", msg, usage.count(&keys[i])).into()
+                }
+                _ => msg.into(),
+            }
+        })
+        .collect();
+    let mut hotkeys: Vec<_> = order
+        .iter()
+        .map(|&i| params.hotkeys.get(&keys[i]).copied())
+        .collect();
+    // The zero field is selected like a field with the `0` hotkey, after the other fields.
+    if params.zero.is_some() {
+        hotkeys.push(Some('0'));
+    }
+
+    Entries {
+        order,
+        labels,
+        hotkeys,
+    }
+}
+
+/// Prompts the given fields once, then runs the procedure matching the selected field kind.
+///
+/// It returns the depth level resulting from the selected field.
+fn prompt_fields<R: BufRead, W: Write, S: AsRef<str>>(
+    params: &mut RunParams<R, W>,
+    msg: Option<&str>,
+    fields: &[(S, Kind<R, W>)],
+) -> MenuResult<Depth> {
+    let entries = arrange(params, fields);
+
+    // The parent fields of the restored state are entered without prompting the user.
+    if let Some(i) = params.visit.next_restored() {
+        match (fields.get(i), params.zero) {
+            (Some((msg, kind)), _) if kind.is_parent() => {
+                return handle_field(params, i, msg.as_ref(), kind)
+            }
+            (None, Some((msg, kind))) if i == fields.len() && kind.is_parent() => {
+                return handle_field(params, i, msg, kind)
+            }
+            _ => params.visit.cancel_restore(),
+        }
+    }
+    params.visit.shown();
+
+    #[cfg(feature = "crossterm")]
+    if params.stream.is_arrows() {
+        return prompt_arrows(params, msg, fields, &entries);
+    }
+
+    let mut pages = Pages::new(params.fmt.page_size, fields.len());
+    show_menu(params, msg, &entries, &pages)?;
+
+    let i = loop {
+        match select(
+            params.stream,
+            params.fmt,
+            params.fmt.suffix,
+            fields.len(),
+            pages.is_paged(),
+            &entries.hotkeys,
+        )? {
+            Choice::Index(i) => break i,
+            Choice::Next if pages.next() => show_menu(params, msg, &entries, &pages)?,
+            Choice::Prev if pages.prev() => show_menu(params, msg, &entries, &pages)?,
+            Choice::Help => show_description(params)?,
+            _ => continue,
+        }
+    };
+
+    handle_choice(params, fields, &entries, i)
+}
+
+/// Lets the user select a field with the arrow keys, then runs the procedure matching
+/// the selected field kind.
+///
+/// If the user pressed `Esc`, the menu is displayed again.
+#[cfg(feature = "crossterm")]
+fn prompt_arrows<R: BufRead, W: Write, S: AsRef<str>>(
+    params: &mut RunParams<R, W>,
+    msg: Option<&str>,
+    fields: &[(S, Kind<R, W>)],
+    entries: &Entries,
+) -> MenuResult<Depth> {
+    clear_screen(params)?;
+    show_notices(params)?;
+    show_title(params, msg)?;
+    let labels: Vec<&str> = entries
+        .labels
+        .iter()
+        .map(AsRef::as_ref)
+        .chain(params.zero.map(|(msg, _)| *msg))
+        .collect();
+
+    let start = mem::take(&mut params.highlight);
+    let announce = params.stream.is_announcing();
+    match select_arrows(
+        params.stream,
+        params.fmt,
+        &labels,
+        &entries.hotkeys,
+        start,
+        &mut params.wait,
+        announce,
+    )? {
+        Choice::Index(i) => handle_choice(params, fields, entries, i),
+        Choice::Refresh(i) => {
+            // The title is erased too, so the menu is displayed again at the same place.
+            erase_title(params.stream, params.fmt, msg)?;
+            params.highlight = i;
+            Ok(Depth::Current)
+        }
+        Choice::Timeout => Err(MenuError::Timeout),
+        _ => Ok(Depth::Current),
+    }
+}
+
+/// Erases the title displayed above the fields selected with the arrow keys, if any.
+///
+/// The title spans as many lines as its prefix and its message contain.
+#[cfg(feature = "crossterm")]
+fn erase_title<W: Write>(writer: &mut W, fmt: &Format<'_>, msg: Option<&str>) -> MenuResult {
+    use crossterm::{
+        cursor::MoveUp,
+        queue,
+        terminal::{Clear, ClearType},
+    };
+
+    if let Some(s) = msg {
+        let lines = fmt.prefix.matches('\n').count() + s.matches('\n').count() + 1;
+        queue!(
+            writer,
+            MoveUp(lines as u16),
+            Clear(ClearType::FromCursorDown)
+        )?;
+    }
+    Ok(())
+}
+
+/// Runs the procedure matching the field displayed at the given index.
+///
+/// The index after the fields corresponds to the zero field, if any.
+fn handle_choice<R: BufRead, W: Write, S: AsRef<str>>(
+    params: &mut RunParams<R, W>,
+    fields: &[(S, Kind<R, W>)],
+    entries: &Entries,
+    i: usize,
+) -> MenuResult<Depth> {
+    let (msg, kind) = match (fields.get(i), params.zero) {
+        (None, Some((msg, kind))) => return handle_field(params, i, msg, kind),
+        _ => &fields[entries.order[i]],
+    };
+
+    let key = params.key(msg.as_ref());
+    log_event!(info, "selected the field `{}`", key);
+    if let Some(ref mut usage) = params.usage {
+        usage.record(key)?;
+    }
+
+    handle_field(params, entries.order[i], msg.as_ref(), kind)
+}
+
 /// Recursive function used to run the current prompt state of the menu.
 ///
 /// It prints out to the stream the fields next to their indexes, then asks the user to
@@ -600,22 +1596,31 @@ fn run_with<R: BufRead, W: Write>(
     fields: Fields<R, W>,
 ) -> MenuResult<Depth> {
     loop {
-        show_menu(params, msg, fields)?;
-
-        // Gets the message and the field kind selected by the user.
-        let (msg, kind) = loop {
-            match select(params.stream, params.fmt.suffix, fields.len())?
-                .and_then(|i| fields.get(i))
-            {
-                Some(field) => break field,
-                None => continue,
-            }
-        };
+        match prompt_fields(params, msg, fields)? {
+            Depth::Current => (),
+            depth => return Ok(depth),
+        }
+    }
+}
+
+/// Runs the current prompt state of a dynamic menu.
+///
+/// It behaves like the [`run_with`] function, but the fields are generated
+/// by the given function before each display of the menu.
+fn run_dynamic_with<R: BufRead, W: Write>(
+    params: &mut RunParams<R, W>,
+    msg: Option<&str>,
+    f: &DynFields<R, W>,
+) -> MenuResult<Depth> {
+    loop {
+        let fields = f(params.stream);
+        if fields.is_empty() {
+            return Ok(Depth::Back(0));
+        }
 
-        match handle_field(params, msg, kind)? {
-            Depth::Quit => return Ok(Depth::Quit),
-            Depth::Back(i) => return Ok(Depth::Back(i)),
+        match prompt_fields(params, msg, &fields)? {
             Depth::Current => (),
+            depth => return Ok(depth),
         }
     }
 }
@@ -0,0 +1,140 @@
+use std::collections::VecDeque;
+use std::fmt::{self, Display, Formatter};
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+/// The submenu visited by the user, identified by the indexes of its parent fields
+/// from the top-level menu.
+///
+/// It is retrieved after running a menu, and can be persisted as a string like `2/0`,
+/// to restore the last visited submenu on the next run
+/// (see [`RawMenu::restore_state`](crate::menu::RawMenu::restore_state)).
+/// The top-level menu corresponds to an empty path.
+///
+/// The indexes are the positions of the fields in the list given to the menu,
+/// regardless of the order they are displayed in.
+///
+/// # Example
+///
+/// ```
+/// # use ezmenulib::prelude::*;
+/// let state: MenuState = "2/0".parse().unwrap();
+/// assert_eq!(state.path(), &[2, 0]);
+/// assert_eq!(state.to_string(), "2/0");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct MenuState {
+    path: Vec<usize>,
+}
+
+impl MenuState {
+    /// Returns the state of the top-level menu.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the indexes of the parent fields of the visited submenu.
+    pub fn path(&self) -> &[usize] {
+        &self.path
+    }
+}
+
+impl From<Vec<usize>> for MenuState {
+    fn from(path: Vec<usize>) -> Self {
+        Self { path }
+    }
+}
+
+impl Display for MenuState {
+    /// Displays the indexes separated by `/`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut indexes = self.path.iter();
+        if let Some(first) = indexes.next() {
+            write!(f, "{}", first)?;
+            for i in indexes {
+                write!(f, "/{}", i)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for MenuState {
+    type Err = ParseIntError;
+
+    /// Parses the indexes separated by `/`, an empty string being the top-level menu.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Ok(Self::new());
+        }
+        s.split('/')
+            .map(str::parse)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::from)
+    }
+}
+
+/// Tracks the submenus visited while a menu is running.
+#[derive(Debug, Default)]
+pub(crate) struct Visit {
+    /// The indexes of the parent fields of the current menu.
+    current: Vec<usize>,
+    /// The indexes of the parent fields of the last displayed menu.
+    last: Vec<usize>,
+    /// The indexes of the parent fields still to enter to restore a state.
+    restore: VecDeque<usize>,
+}
+
+impl Visit {
+    /// Returns the visit starting by entering the submenu of the given state.
+    pub(crate) fn new(state: &MenuState) -> Self {
+        Self {
+            restore: state.path.iter().copied().collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Enters the submenu of the parent field at the given index.
+    pub(crate) fn enter(&mut self, i: usize) {
+        self.current.push(i);
+    }
+
+    /// Leaves the current submenu.
+    pub(crate) fn leave(&mut self) {
+        self.current.pop();
+    }
+
+    /// Marks the current menu as displayed.
+    pub(crate) fn shown(&mut self) {
+        self.last.clone_from(&self.current);
+    }
+
+    /// Returns the amount of submenus entered from the top-level menu.
+    #[cfg(all(feature = "tui", any(feature = "crossterm", feature = "termion")))]
+    pub(crate) fn depth(&self) -> usize {
+        self.current.len()
+    }
+
+    /// Starts restoring the given state from the top-level menu, replacing the state
+    /// being restored.
+    #[cfg(all(feature = "tui", any(feature = "crossterm", feature = "termion")))]
+    pub(crate) fn goto(&mut self, state: &MenuState) {
+        self.restore = state.path.iter().copied().collect();
+    }
+
+    /// Returns the index of the next parent field to enter to restore the state, if any.
+    pub(crate) fn next_restored(&mut self) -> Option<usize> {
+        self.restore.pop_front()
+    }
+
+    /// Stops restoring the state, e.g. if a field of its path is not a parent field anymore.
+    pub(crate) fn cancel_restore(&mut self) {
+        self.restore.clear();
+    }
+
+    /// Returns the state of the last displayed menu.
+    pub(crate) fn state(&self) -> MenuState {
+        MenuState::from(self.last.clone())
+    }
+}
@@ -1,6 +1,12 @@
+use crate::field::{Format, Style};
+use crate::utils::read_input;
+use crate::MenuResult;
 use std::fmt;
 use std::fmt::Arguments;
-use std::io::{self, stdin, stdout, BufRead, BufReader, IoSlice, IoSliceMut, Read, Write};
+use std::fs::{File, OpenOptions};
+use std::io::{
+    self, stdin, stdout, BufRead, BufReader, IoSlice, IoSliceMut, IsTerminal, Read, Write,
+};
 use std::ops::{Deref, DerefMut};
 
 macro_rules! map_impl {
@@ -120,6 +126,12 @@ impl<T> DerefMut for Mutable<'_, T> {
 /// Although, you cannot wrap and instantiate if the stream does not own the reader,
 /// because `BufReader` needs to own it.
 ///
+/// ## Colors
+///
+/// The [style](crate::field::Style) of the fields is only applied if the stream is colored.
+/// The default stream is colored if the standard output is a terminal,
+/// and the other streams are not colored unless you use the [`MenuStream::colored`] method.
+///
 /// ## Inheritance
 ///
 /// You may give a mutable reference to the streams instead of giving the ownership.
@@ -138,12 +150,55 @@ impl<T> DerefMut for Mutable<'_, T> {
 pub struct MenuStream<'a, R = super::In, W = super::Out> {
     reader: Mutable<'a, R>,
     writer: Mutable<'a, W>,
+    colored: bool,
+    notices: Vec<Notice>,
+    #[cfg(feature = "crossterm")]
+    arrows: bool,
+    #[cfg(feature = "crossterm")]
+    announce: bool,
+}
+
+/// A status message posted to the stream, printed above the next menu render.
+#[derive(Debug)]
+pub(crate) struct Notice {
+    pub(crate) msg: String,
+    pub(crate) error: bool,
 }
 
 impl Default for MenuStream<'_> {
     #[inline]
     fn default() -> Self {
-        Self::wrap_reader(stdin(), stdout())
+        let colored = stdout().is_terminal();
+        Self::wrap_reader(stdin(), stdout()).colored(colored)
+    }
+}
+
+impl MenuStream<'_, BufReader<File>, File> {
+    /// Instantiates the stream reading from and writing to the controlling terminal.
+    ///
+    /// It opens `/dev/tty` on Unix systems, and the console on Windows. This way, the fields
+    /// are still prompted to the user even if the standard input or output is redirected,
+    /// e.g. to prompt a secret while the output of the program is piped to a file.
+    /// The stream is colored.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let token: String = Written::from("API token").prompt(&mut MenuStream::tty()?)?;
+    /// println!("{}", token);
+    /// # Ok(()) }
+    /// ```
+    pub fn tty() -> io::Result<Self> {
+        #[cfg(windows)]
+        let (input, output) = ("CONIN$", "CONOUT$");
+        #[cfg(not(windows))]
+        let (input, output) = ("/dev/tty", "/dev/tty");
+
+        let reader = File::open(input)?;
+        let writer = OpenOptions::new().write(true).open(output)?;
+        Ok(Self::wrap_reader(reader, writer).colored(true))
     }
 }
 
@@ -161,6 +216,12 @@ impl<'a, R, W> MenuStream<'a, R, W> {
         Self {
             reader: Mutable::Owned(reader),
             writer: Mutable::Owned(writer),
+            colored: false,
+            notices: Vec::new(),
+            #[cfg(feature = "crossterm")]
+            arrows: false,
+            #[cfg(feature = "crossterm")]
+            announce: false,
         }
     }
 
@@ -169,6 +230,130 @@ impl<'a, R, W> MenuStream<'a, R, W> {
         Self {
             reader: Mutable::Borrowed(reader),
             writer: Mutable::Borrowed(writer),
+            colored: false,
+            notices: Vec::new(),
+            #[cfg(feature = "crossterm")]
+            arrows: false,
+            #[cfg(feature = "crossterm")]
+            announce: false,
+        }
+    }
+
+    /// Defines if the [style](crate::field::Style) of the fields is applied
+    /// when writing to the stream.
+    ///
+    /// The ANSI escape codes are only written if the `"color"` feature is enabled.
+    pub fn colored(mut self, colored: bool) -> Self {
+        self.colored = colored;
+        self
+    }
+
+    /// Returns true if the style of the fields is applied when writing to the stream.
+    #[inline]
+    pub fn is_colored(&self) -> bool {
+        self.colored
+    }
+
+    /// Defines if the user selects the values with the arrow keys (`false` by default).
+    ///
+    /// If it does, the selectable values and the menus highlight a value that the user moves
+    /// with the arrow keys, then selects by pressing `Enter`, instead of typing its index.
+    /// The key events are read from the terminal with `crossterm`, not from the reader
+    /// of the stream, so it should only be enabled if the standard input is a terminal,
+    /// otherwise the values are selected by typing their index:
+    /// ```no_run
+    /// use std::io::{stdin, IsTerminal};
+    /// use ezmenulib::menu::MenuStream;
+    ///
+    /// let stream = MenuStream::default().arrows(stdin().is_terminal());
+    /// ```
+    #[cfg(feature = "crossterm")]
+    #[cfg_attr(nightly, doc(cfg(feature = "crossterm")))]
+    pub fn arrows(mut self, arrows: bool) -> Self {
+        self.arrows = arrows;
+        self
+    }
+
+    /// Returns true if the user selects the values with the arrow keys.
+    #[cfg(feature = "crossterm")]
+    #[cfg_attr(nightly, doc(cfg(feature = "crossterm")))]
+    #[inline]
+    pub fn is_arrows(&self) -> bool {
+        self.arrows
+    }
+
+    /// Defines if the highlighted value is announced when selecting the values
+    /// with the [arrow keys](MenuStream::arrows) (`false` by default).
+    ///
+    /// If it is, a plain sentence like `Selected 2 of 5: Settings` is written on its own line
+    /// below the values each time the highlight moves, so the screen readers tracking
+    /// the output of the terminal can read it.
+    #[cfg(feature = "crossterm")]
+    #[cfg_attr(nightly, doc(cfg(feature = "crossterm")))]
+    pub fn announce(mut self, announce: bool) -> Self {
+        self.announce = announce;
+        self
+    }
+
+    /// Returns true if the highlighted value is announced when selecting the values
+    /// with the arrow keys.
+    #[cfg(feature = "crossterm")]
+    #[cfg_attr(nightly, doc(cfg(feature = "crossterm")))]
+    #[inline]
+    pub fn is_announcing(&self) -> bool {
+        self.announce
+    }
+
+    /// Posts a status message, printed above the menu the next time it is displayed.
+    ///
+    /// It is useful for the functions mapped to the fields of a [`RawMenu`](crate::menu::RawMenu),
+    /// so their result is not scrolled away when the menu is displayed again. The messages
+    /// are kept in the stream until a menu displays them.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// let save = |s: &mut MenuStream| {
+    ///     std::fs::write("settings.toml", "")?;
+    ///     s.notify("Saved!");
+    ///     Ok(())
+    /// };
+    /// RawMenu::from(&[("Save", Kind::Map(&save)), ("Quit", Kind::Quit)])
+    ///     .run()
+    ///     .unwrap();
+    /// ```
+    pub fn notify<S: Into<String>>(&mut self, msg: S) {
+        self.notices.push(Notice {
+            msg: msg.into(),
+            error: false,
+        });
+    }
+
+    /// Posts a status message printed with the error style of the menu format,
+    /// above the menu the next time it is displayed (see [`MenuStream::notify`]).
+    pub fn notify_error<S: Into<String>>(&mut self, msg: S) {
+        self.notices.push(Notice {
+            msg: msg.into(),
+            error: true,
+        });
+    }
+
+    /// Returns the status messages posted to the stream, removing them from it.
+    pub(crate) fn take_notices(&mut self) -> Vec<Notice> {
+        std::mem::take(&mut self.notices)
+    }
+
+    /// Returns the format to use when writing to the stream, without any style
+    /// if the stream is not colored.
+    pub(crate) fn output_format<'f>(&self, fmt: &Format<'f>) -> Format<'f> {
+        Format {
+            style: if self.colored {
+                fmt.style
+            } else {
+                Style::PLAIN
+            },
+            ..fmt.clone()
         }
     }
 
@@ -184,6 +369,48 @@ impl<'a, R, W> MenuStream<'a, R, W> {
     }
 }
 
+impl<R: BufRead, W: Write> MenuStream<'_, R, W> {
+    /// Displays the given text page by page, like a pager.
+    ///
+    /// It displays `height` lines at a time, then waits for the user to press `Enter`
+    /// before displaying the next page, or to enter `q` to stop. After the last page,
+    /// it waits for the user to press `Enter` again. This is useful for the long outputs
+    /// of the functions mapped to the fields of a [`RawMenu`](crate::menu::RawMenu),
+    /// so they are not scrolled away when the menu is displayed again.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// let log = |s: &mut MenuStream| {
+    ///     let output = std::fs::read_to_string("deploy.log")?;
+    ///     s.page(output, 20)
+    /// };
+    /// RawMenu::from(&[("Show the logs", Kind::Map(&log)), ("Quit", Kind::Quit)])
+    ///     .run()
+    ///     .unwrap();
+    /// ```
+    pub fn page<T: AsRef<str>>(&mut self, text: T, height: usize) -> MenuResult {
+        let lines: Vec<&str> = text.as_ref().lines().collect();
+        let mut pages = lines.chunks(height.max(1)).peekable();
+        while let Some(page) = pages.next() {
+            for line in page {
+                writeln!(self, "{}", line)?;
+            }
+
+            match pages.peek() {
+                Some(_) => write!(self, "-- more (press Enter, or q to stop) --")?,
+                None => write!(self, "(END)")?,
+            }
+            self.flush()?;
+            if read_input(self)? == "q" {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
 impl<R: Read, W> Read for MenuStream<'_, R, W> {
     map_impl!(
         reader,
@@ -26,3 +26,23 @@ fn borrow_params() -> Result<(), Box<dyn Error>> {
     let output = String::from_utf8(output)?;
     Ok(assert_eq!(output, "hey\n"))
 }
+
+#[test]
+fn page() -> Result<(), Box<dyn Error>> {
+    let mut input = "\n\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut stream = MenuStream::with(&mut input, &mut output);
+    stream.page("a\nb\nc", 2)?;
+    let mut input = "q\n".as_bytes();
+    let mut stopped = Vec::<u8>::new();
+    MenuStream::with(&mut input, &mut stopped).page("a\nb\nc", 2)?;
+
+    assert_eq!(
+        String::from_utf8(output)?,
+        "a\nb\n-- more (press Enter, or q to stop) --c\n(END)"
+    );
+    Ok(assert_eq!(
+        String::from_utf8(stopped)?,
+        "a\nb\n-- more (press Enter, or q to stop) --"
+    ))
+}
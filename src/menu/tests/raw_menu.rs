@@ -0,0 +1,528 @@
+use std::error::Error;
+use std::io::Write;
+
+use crate::prelude::*;
+
+type Res = Result<(), Box<dyn Error>>;
+
+macro_rules! test_menu {
+    ($input:expr, $fields:expr $(, $meth:ident($($arg:expr),*))* $(,)?) => {{
+        let mut input = $input.as_bytes();
+        let mut output = Vec::<u8>::new();
+        let mut stream = MenuStream::with(&mut input, &mut output);
+        RawMenu::borrowed(&mut stream, $fields)
+            $(.$meth($($arg),*))*
+            .run()?;
+        String::from_utf8(output)
+    }};
+}
+
+#[test]
+fn dynamic_fields() -> Res {
+    let count = std::cell::Cell::new(0);
+    let gen = |_: &mut MenuStream<&[u8], Vec<u8>>| {
+        count.set(count.get() + 1);
+        vec![
+            (format!("refresh {}", count.get()), Kind::Back(0)),
+            ("back".to_owned(), Kind::Back(1)),
+        ]
+    };
+
+    let output = test_menu!(
+        "1\n1\n2\n2\n",
+        &[("dyn", Kind::Dynamic(&gen)), ("quit", Kind::Quit)],
+    )?;
+
+    Ok(assert_eq!(
+        output,
+        "[1] - dyn
+[2] - quit
+>> --> dyn
+[1] - refresh 1
+[2] - back
+>> --> dyn
+[1] - refresh 2
+[2] - back
+>> [1] - dyn
+[2] - quit
+>> "
+    ))
+}
+
+#[test]
+fn dynamic_no_field() -> Res {
+    let gen = |s: &mut MenuStream<&[u8], Vec<u8>>| {
+        writeln!(s, "nothing to show").unwrap();
+        Vec::new()
+    };
+
+    let output = test_menu!(
+        "1\n2\n",
+        &[("dyn", Kind::Dynamic(&gen)), ("quit", Kind::Quit)],
+    )?;
+
+    Ok(assert_eq!(
+        output,
+        "[1] - dyn
+[2] - quit
+>> nothing to show
+[1] - dyn
+[2] - quit
+>> "
+    ))
+}
+
+#[test]
+fn max_label_width() -> Res {
+    let output = test_menu!(
+        "1\n",
+        &[("a very long label", Kind::Quit)],
+        format(Format::max_label_width(Some(8))),
+    )?;
+
+    Ok(assert_eq!(output, "[1] - a very …\n>> "))
+}
+
+#[test]
+fn dyn_menus() -> Res {
+    let mut input = "1\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut stream = MenuStream::with(&mut input, &mut output);
+    {
+        let mut menus: Vec<Box<dyn DynMenu + '_>> = vec![Box::new(RawMenu::borrowed(
+            &mut stream,
+            &[("first", Kind::Quit)],
+        ))];
+        for menu in menus.iter_mut() {
+            menu.run()?;
+        }
+    }
+
+    Ok(assert_eq!(String::from_utf8(output)?, "[1] - first\n>> "))
+}
+
+#[test]
+fn page_size() -> Res {
+    let output = test_menu!(
+        "n\n2\n",
+        &[("first", Kind::Back(0)), ("second", Kind::Quit)],
+        format(Format::page_size(Some(1))),
+    )?;
+
+    Ok(assert_eq!(
+        output,
+        "[1] - first\n[n] - next page\n>> [2] - second\n[p] - previous page\n>> "
+    ))
+}
+
+#[test]
+fn usage() -> Res {
+    let path = std::env::temp_dir().join("ezmenulib_usage_test");
+    let _ = std::fs::remove_file(&path);
+
+    let output = test_menu!(
+        "2\n1\n2\n",
+        &[
+            ("a", Kind::Quit),
+            ("sub", Kind::Parent(&[("x", Kind::Back(1))])),
+        ],
+        usage(Usage::load(&path)?.sorted(true).annotated(true)),
+    )?;
+
+    assert_eq!(
+        output,
+        "[1] - a (0)
+[2] - sub (0)
+>> --> sub
+[1] - x (0)
+>> [1] - sub (1)
+[2] - a (0)
+>> "
+    );
+
+    let usage = Usage::load(&path)?;
+    std::fs::remove_file(&path)?;
+    assert_eq!(usage.count("a"), 1);
+    assert_eq!(usage.count("sub"), 1);
+    Ok(assert_eq!(usage.count("sub/x"), 1))
+}
+
+#[test]
+fn parent_with() -> Res {
+    let output = test_menu!(
+        "1\n1\n1\n2\n2\n",
+        &[
+            (
+                "sub",
+                Kind::ParentWith(
+                    Format::prefix("==> "),
+                    &[
+                        ("inner", Kind::Parent(&[("x", Kind::Back(1))])),
+                        ("back", Kind::Back(1)),
+                    ]
+                )
+            ),
+            ("quit", Kind::Quit),
+        ],
+        format(Format {
+            suffix: "> ",
+            ..Default::default()
+        }),
+    )?;
+
+    Ok(assert_eq!(
+        output,
+        "[1] - sub
+[2] - quit
+> ==> sub
+[1] - inner
+[2] - back
+> ==> inner
+[1] - x
+> ==> sub
+[1] - inner
+[2] - back
+> [1] - sub
+[2] - quit
+> "
+    ))
+}
+
+#[test]
+fn hotkey() -> Res {
+    let output = test_menu!(
+        "1\nb\nq\n",
+        &[
+            ("settings", Kind::Parent(&[("back", Kind::Back(1))])),
+            ("quit", Kind::Quit),
+        ],
+        hotkey("quit", 'q'),
+        hotkey("settings/back", 'b'),
+    )?;
+
+    Ok(assert_eq!(
+        output,
+        "[1] - settings
+[q] - quit
+>> --> settings
+[b] - back
+>> [1] - settings
+[q] - quit
+>> "
+    ))
+}
+
+#[test]
+fn description() -> Res {
+    let output = test_menu!(
+        "?\n1\n?\n1\n2\n",
+        &[
+            ("settings", Kind::Parent(&[("back", Kind::Back(1))])),
+            ("quit", Kind::Quit),
+        ],
+        description("", "Manages the settings."),
+    )?;
+
+    Ok(assert_eq!(
+        output,
+        "[1] - settings
+[2] - quit
+>> Manages the settings.
+>> --> settings
+[1] - back
+>> >> [1] - settings
+[2] - quit
+>> "
+    ))
+}
+
+#[test]
+fn notify() -> Res {
+    let save = |s: &mut MenuStream<&[u8], Vec<u8>>| {
+        s.notify("Saved!");
+        s.notify_error("The backup failed.");
+        Ok(())
+    };
+
+    let output = test_menu!(
+        "1\n2\n",
+        &[("save", Kind::Map(&save)), ("quit", Kind::Quit)]
+    )?;
+
+    Ok(assert_eq!(
+        output,
+        "[1] - save
+[2] - quit
+>> Saved!
+The backup failed.
+[1] - save
+[2] - quit
+>> "
+    ))
+}
+
+#[test]
+fn clear_between() -> Res {
+    let output = test_menu!("1\n", &[("quit", Kind::Quit)], clear_between(true))?;
+    assert_eq!(output, "[1] - quit\n>> ");
+
+    let mut input = "1\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut stream = MenuStream::with(&mut input, &mut output).colored(true);
+    RawMenu::borrowed(&mut stream, &[("quit", Kind::Quit)])
+        .clear_between(true)
+        .run()?;
+    Ok(assert!(String::from_utf8(output)?.starts_with("\x1b[2J")))
+}
+
+#[test]
+fn restore_state() -> Res {
+    fn fields<'a>() -> Fields<'a, &'a [u8], Vec<u8>> {
+        &[
+            ("a", Kind::Quit),
+            (
+                "sub",
+                Kind::Parent(&[
+                    ("inner", Kind::Parent(&[("q", Kind::Quit)])),
+                    ("back", Kind::Back(1)),
+                ]),
+            ),
+        ]
+    }
+
+    let mut input = "1\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let state = {
+        let mut stream = MenuStream::with(&mut input, &mut output);
+        let mut menu = RawMenu::borrowed(&mut stream, fields()).restore_state("1/0".parse()?);
+        menu.run()?;
+        menu.save_state()
+    };
+    assert_eq!(state.path(), &[1, 0]);
+    assert_eq!(String::from_utf8(output)?, "--> inner\n[1] - q\n>> ");
+
+    // The first field is not a parent field, so the top-level menu is displayed.
+    let mut input = "2\n2\n1\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let state = {
+        let mut stream = MenuStream::with(&mut input, &mut output);
+        let mut menu = RawMenu::borrowed(&mut stream, fields()).restore_state("0/3".parse()?);
+        menu.run()?;
+        menu.save_state()
+    };
+    assert_eq!(state, MenuState::new());
+    Ok(assert_eq!(
+        String::from_utf8(output)?,
+        "[1] - a\n[2] - sub\n>> --> sub\n[1] - inner\n[2] - back\n>> [1] - a\n[2] - sub\n>> "
+    ))
+}
+
+#[test]
+fn error_source() {
+    #[derive(Debug)]
+    struct Locked(std::io::Error);
+
+    impl std::fmt::Display for Locked {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str("cannot load the save")
+        }
+    }
+
+    impl Error for Locked {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    let mut input = "1\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut stream = MenuStream::with(&mut input, &mut output);
+    let e = RawMenu::borrowed(
+        &mut stream,
+        &[(
+            "load",
+            Kind::Map(&|_| {
+                let e = std::io::Error::other("the file is locked");
+                Err(MenuError::other(Locked(e)))
+            }),
+        )],
+    )
+    .run()
+    .unwrap_err();
+
+    assert_eq!(e.kind(), MenuErrorKind::Other);
+    assert_eq!(e.to_string(), "cannot load the save");
+    assert_eq!(e.source().unwrap().to_string(), "the file is locked");
+    assert_eq!(MenuError::from("x").kind(), MenuErrorKind::Other);
+}
+
+#[test]
+fn map_navigate() -> Res {
+    let output = test_menu!(
+        "1\n1\n1\n2\n2\n",
+        &[
+            (
+                "settings",
+                Kind::Parent(&[
+                    (
+                        "network",
+                        Kind::Parent(&[(
+                            "save",
+                            Kind::MapNavigate(&|_| Ok(Navigation::Parent("settings".to_owned()))),
+                        )]),
+                    ),
+                    ("back", Kind::Back(1)),
+                ]),
+            ),
+            ("quit", Kind::Quit),
+        ],
+    )?;
+
+    assert_eq!(
+        output,
+        "[1] - settings
+[2] - quit
+>> --> settings
+[1] - network
+[2] - back
+>> --> network
+[1] - save
+>> --> settings
+[1] - network
+[2] - back
+>> [1] - settings
+[2] - quit
+>> "
+    );
+
+    let mut input = "1\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut stream = MenuStream::with(&mut input, &mut output);
+    let res = RawMenu::borrowed(
+        &mut stream,
+        &[(
+            "save",
+            Kind::MapNavigate(&|_| Ok(Navigation::Parent("unknown".to_owned()))),
+        )],
+    )
+    .run();
+    assert!(res.is_err());
+    Ok(())
+}
+
+#[test]
+fn run_for() -> Res {
+    let mut input = "2\n1\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut stream = MenuStream::with(&mut input, &mut output);
+    let port: Option<u16> = RawMenu::borrowed(
+        &mut stream,
+        &[
+            ("none", Kind::Value(&|_| Ok(None))),
+            (
+                "sub",
+                Kind::Parent(&[("https", Kind::Value(&|_| Ok(Some(Box::new(443u16)))))]),
+            ),
+        ],
+    )
+    .run_for()?;
+    assert_eq!(port, Some(443));
+
+    let mut input = "1\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut stream = MenuStream::with(&mut input, &mut output);
+    let out = RawMenu::borrowed(
+        &mut stream,
+        &[("x", Kind::Value(&|_| Ok(Some(Box::new(1u8)))))],
+    )
+    .run_for::<u16>();
+    assert!(out.is_err());
+
+    let mut input = "1\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut stream = MenuStream::with(&mut input, &mut output);
+    let out: Option<u8> = RawMenu::borrowed(&mut stream, &[("quit", Kind::Quit)]).run_for()?;
+    Ok(assert_eq!(out, None))
+}
+
+#[test]
+fn zero() -> Res {
+    let output = test_menu!(
+        "1\n0\n0\n",
+        &[("sub", Kind::Parent(&[("x", Kind::Quit)]))],
+        zero("back", Kind::Back(1)),
+    )?;
+
+    Ok(assert_eq!(
+        output,
+        "[1] - sub
+[0] - back
+>> --> sub
+[1] - x
+[0] - back
+>> [1] - sub
+[0] - back
+>> "
+    ))
+}
+
+#[test]
+fn cancelled() {
+    let mut input = "3\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut stream = MenuStream::with(&mut input, &mut output);
+    let res = RawMenu::borrowed(&mut stream, &[("a", Kind::Quit), ("b", Kind::Quit)]).run();
+    assert!(matches!(res, Err(MenuError::Cancelled)));
+}
+
+#[test]
+fn parent_titled() -> Res {
+    let output = test_menu!(
+        "1\n1\n2\n",
+        &[
+            (
+                "advanced",
+                Kind::ParentTitled("Advanced settings", &[("back", Kind::Back(1))])
+            ),
+            ("quit", Kind::Quit),
+        ],
+    )?;
+
+    Ok(assert_eq!(
+        output,
+        "[1] - advanced
+[2] - quit
+>> --> Advanced settings
+[1] - back
+>> [1] - advanced
+[2] - quit
+>> "
+    ))
+}
+
+#[cfg(feature = "crossterm")]
+#[test]
+fn refresh_erase() -> Res {
+    use super::super::erase_title;
+    use crossterm::{
+        cursor::MoveUp,
+        queue,
+        terminal::{Clear, ClearType},
+    };
+
+    let erased = |fmt: &Format, msg| -> Result<Vec<u8>, Box<dyn Error>> {
+        let mut out = Vec::new();
+        erase_title(&mut out, fmt, msg)?;
+        Ok(out)
+    };
+    let mut expected = Vec::new();
+    queue!(expected, MoveUp(3), Clear(ClearType::FromCursorDown))?;
+
+    assert!(erased(&Format::default(), None)?.is_empty());
+    // The title spans a header line, then 2 lines.
+    let fmt = Format {
+        prefix: "Settings\n--> ",
+        ..Default::default()
+    };
+    Ok(assert_eq!(erased(&fmt, Some("Status\nUptime"))?, expected))
+}
@@ -1,4 +1,6 @@
+use std::collections::HashMap;
 use std::error::Error;
+use std::time::{Duration, Instant};
 
 #[cfg(feature = "date")]
 use crate::chrono::prelude::*;
@@ -142,6 +144,67 @@ fn ask_until() -> Res {
     Ok(assert_eq!(output, "--> age\n>> >> >> >> "))
 }
 
+#[test]
+fn written_validated() -> Res {
+    let output = test_menu! {
+        menu,
+        "80\nabc\n8080\n",
+        let port = menu.written_validated(&Written::from("port"), |p: &u16| {
+            if *p >= 1024 { Ok(()) } else { Err(format!("{} is a privileged port", p)) }
+        })?,
+        assert_eq!(port, 8080),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> port\n>> 80 is a privileged port\n>> >> "
+    ))
+}
+
+#[test]
+fn written_parsed() -> Res {
+    let parse = |s: &str| match s.split_once(':') {
+        Some((h, m)) => Ok((h.parse::<u8>()?, m.parse::<u8>()?)),
+        None => Ok((s.parse()?, 0)),
+    };
+    let output = test_menu! {
+        menu,
+        "9h\n9:30\n",
+        let time: (u8, u8) = menu.written_parsed(
+            &Written::from("time"),
+            |s| parse(s).map_err(|e: std::num::ParseIntError| e.to_string()),
+        )?,
+        assert_eq!(time, (9, 30)),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> time\n>> invalid digit found in string\n>> "
+    ))
+}
+
+#[test]
+fn repeated() -> Res {
+    let output = test_menu! {
+        menu,
+        "Ahmad\n19\nyes\nBob\n30\n\n",
+        let people: Vec<(String, u8)> = menu.repeated(
+            &Written::from("another").default_value("no"),
+            |menu| Ok((
+                menu.written(&Written::from("name"))?,
+                menu.written(&Written::from("age"))?,
+            )),
+        )?,
+        assert_eq!(people, vec![("Ahmad".to_owned(), 19), ("Bob".to_owned(), 30)]),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> name\n>> --> age\n>> --> another (default: no)\n>> \
+--> name\n>> --> age\n>> --> another (default: no)\n>> "
+    ))
+}
+
 #[test]
 fn optional_written() -> Res {
     let written = Written::from("age");
@@ -279,3 +342,776 @@ fn select_default() -> Res {
 >> "
     ))
 }
+
+#[test]
+fn max_label_width() -> Res {
+    let output = test_menu! {
+        menu,
+        "1\n",
+        let name = menu.selected(
+            Selected::new("select the été", [("a very long label", 0), ("été", 1), ("créé", 2)])
+                .format(Format::max_label_width(Some(4)))
+        )?,
+        assert_eq!(name, 0),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> select the été
+[1] - a v…
+[2] - été
+[3] - créé
+>> "
+    ))
+}
+
+#[test]
+fn select_default_index() -> Res {
+    let output = test_menu! {
+        menu,
+        "\n",
+        let name = menu.selected(Selected::new("select the type", [("a", 0), ("b", 1)]).default(1))?,
+        assert_eq!(name, 1),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> select the type\n[1] - a\n[2] - b (default)\n>> "
+    ))
+}
+
+#[test]
+fn custom_labels() -> Res {
+    let fmt = Format {
+        optional_label: "facultatif",
+        default_label: "défaut",
+        example_label: "exemple",
+        ..Default::default()
+    };
+
+    let output = test_menu! {
+        menu,
+        "\n",
+        let age: u8 = menu.written_or_default(
+            &Written::from("votre âge").example("19").default_value("18").format(fmt.clone())
+        ),
+        assert_eq!(age, 18),
+    }?;
+    assert_eq!(output, "--> votre âge (exemple: 19, défaut: 18)\n>> ");
+
+    let output = test_menu! {
+        menu,
+        "\n",
+        let name = menu.optional_selected(
+            Selected::new("le type", [("a", 0), ("b", 1)]).format(fmt.clone())
+        )?,
+        assert_eq!(name, None),
+    }?;
+    assert_eq!(output, "--> le type (facultatif)\n[1] - a\n[2] - b\n>> ");
+
+    let output = test_menu! {
+        menu,
+        "\n",
+        let name = menu.selected(Selected::new("le type", [("a", 0), ("b", 1)]).format(fmt).default(1))?,
+        assert_eq!(name, 1),
+    }?;
+    Ok(assert_eq!(
+        output,
+        "--> le type\n[1] - a\n[2] - b (défaut)\n>> "
+    ))
+}
+
+#[test]
+fn compact_hints() -> Res {
+    let output = test_menu! {
+        menu,
+        "\n\n\n",
+        menu = menu.hide_examples().show_optional_hints(false),
+        let age: u8 = menu.written_or_default(&Written::from("your age please").example("19").default_value("18")),
+        assert_eq!(age, 18),
+        let name: Option<String> = menu.optional_written(&Written::from("your name please").example("Ahmad"))?,
+        assert_eq!(name, None),
+        let kind = menu.optional_selected(Selected::new("select the type", [("a", 0)]))?,
+        assert_eq!(kind, None),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> your age please (default: 18)\n>> --> your name please\n>> --> select the type\n[1] - a\n>> "
+    ))
+}
+
+#[test]
+fn page_size() -> Res {
+    let output = test_menu! {
+        menu,
+        "p\nn\nn\n3\n",
+        let name = menu.selected(
+            Selected::new("select the type", [("a", 0), ("b", 1), ("c", 2)])
+                .format(Format::page_size(Some(2)))
+        )?,
+        assert_eq!(name, 2),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> select the type
+[1] - a
+[2] - b
+[n] - next page
+>> >> --> select the type
+[3] - c
+[p] - previous page
+>> >> "
+    ))
+}
+
+#[test]
+fn default_from() -> Res {
+    let profile = HashMap::from([("age", "19"), ("type", "BSD")]);
+    let output = test_menu! {
+        menu,
+        "\n\n",
+        let age: u8 = menu.written_or_default(
+            &Written::from("your age please").default_from(&profile, "age")
+        ),
+        assert_eq!(age, 19),
+        let name: Type2 = menu.selected_or_default(
+            Selected::from("select the type").default_from(&profile, "type")
+        ),
+        assert_eq!(name, Type2::BSD),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> your age please (default: 19)
+>> --> select the type
+[1] - MIT
+[2] - GPL
+[3] - BSD (default)
+>> "
+    ))
+}
+
+#[test]
+fn with_answers() -> Res {
+    let mut input = "19\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut menu =
+        Values::from(MenuStream::with(&mut input, &mut output)).with_answers(HashMap::from([
+            ("your name please".to_owned(), "Ahmad".to_owned()),
+            ("select the type".to_owned(), "GPL".to_owned()),
+            ("your height please".to_owned(), "tall".to_owned()),
+        ]));
+
+    let name: String = menu.written(&Written::from("your name please"))?;
+    assert_eq!(name, "Ahmad");
+    let age: u8 = menu.written(&Written::from("your age please"))?;
+    assert_eq!(age, 19);
+    let license: Type2 = menu.selected(Selected::from("select the type"))?;
+    assert_eq!(license, Type2::GPL);
+    let height: MenuResult<u8> = menu.written(&Written::from("your height please"));
+    assert_eq!(height, Err(MenuError::Input));
+    drop(menu);
+
+    Ok(assert_eq!(
+        String::from_utf8(output)?,
+        "--> your age please\n>> "
+    ))
+}
+
+#[test]
+fn unit() -> Res {
+    let output = test_menu! {
+        menu,
+        "12 GB\n512 mb \n64MB, 128\n",
+        let size: u32 = menu.written(&Written::from("size").unit("MB"))?,
+        assert_eq!(size, 512),
+        let sizes: Vec<u32> = menu.many_written(&Written::from("sizes").unit("MB").example("64, 128"), ", ")?,
+        assert_eq!(sizes, [64, 128]),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> size (unit: MB)\n>> >> --> sizes (unit: MB, example: 64, 128)\n>> "
+    ))
+}
+
+#[test]
+fn style() -> Res {
+    let fmt = Format::style(Style {
+        prefix: Some(Color::Cyan),
+        error: Some(Color::Red),
+        dim_default: true,
+    });
+
+    let mut input = "18\n20\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut menu =
+        Values::from(MenuStream::with(&mut input, &mut output).colored(true)).format(fmt.clone());
+    let age: u8 = menu.written_validated(
+        &Written::from("your age please").default_value("20"),
+        |age| if *age > 18 { Ok(()) } else { Err("too young") },
+    )?;
+    assert_eq!(age, 20);
+    drop(menu);
+
+    let expected = if cfg!(feature = "color") {
+        "\x1b[36m--> \x1b[0myour age please (default: \x1b[2m20\x1b[0m)
+>> \x1b[31mtoo young\x1b[0m
+>> "
+    } else {
+        "--> your age please (default: 20)\n>> too young\n>> "
+    };
+    assert_eq!(String::from_utf8(output)?, expected);
+
+    // The style is not applied if the stream is not colored.
+    let output = test_menu! {
+        menu,
+        "1\n",
+        let license: Type2 = menu.selected(Selected::from("select the type").format(fmt).default(0))?,
+        assert_eq!(license, Type2::MIT),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> select the type\n[1] - MIT (default)\n[2] - GPL\n[3] - BSD\n>> "
+    ))
+}
+
+#[test]
+fn inline_suffix() -> Res {
+    let fmt = Format {
+        suffix: ": ",
+        line_brk: false,
+        ..Default::default()
+    };
+
+    let mut input = "2\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut menu = Values::from(MenuStream::with(&mut input, &mut output).colored(true));
+    let license: Type2 = menu.selected(Selected::from("select the type").format(fmt.clone()))?;
+    assert_eq!(license, Type2::GPL);
+    drop(menu);
+
+    // The cursor is only moved with the `color` feature.
+    let expected = if cfg!(feature = "color") {
+        "--> select the type: \n[1] - MIT\n[2] - GPL\n[3] - BSD\n\x1b[3F\x1b[22G\x1b[3E"
+    } else {
+        "--> select the type\n[1] - MIT\n[2] - GPL\n[3] - BSD\n>> "
+    };
+    assert_eq!(String::from_utf8(output)?, expected);
+
+    // The default suffix is used on its own line if the stream is not colored.
+    let output = test_menu! {
+        menu,
+        "2\n",
+        let license: Type2 = menu.selected(Selected::from("select the type").format(fmt))?,
+        assert_eq!(license, Type2::GPL),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> select the type\n[1] - MIT\n[2] - GPL\n[3] - BSD\n>> "
+    ))
+}
+
+#[test]
+fn index_style() -> Res {
+    let output = test_menu! {
+        menu,
+        "B\n",
+        let license: Type2 = menu.selected(Selected::from("select the type").format(Format {
+            left_sur: "",
+            right_sur: ")",
+            chip: " ",
+            indent: 2,
+            index_style: IndexStyle::Alphabetic,
+            ..Default::default()
+        }))?,
+        assert_eq!(license, Type2::GPL),
+    }?;
+    assert_eq!(
+        output,
+        "--> select the type\n  a) MIT\n  b) GPL\n  c) BSD\n>> "
+    );
+
+    let output = test_menu! {
+        menu,
+        "iii\n",
+        let license: Type2 = menu.selected(
+            Selected::from("select the type").format(Format::index_style(IndexStyle::Roman)),
+        )?,
+        assert_eq!(license, Type2::BSD),
+    }?;
+    assert_eq!(
+        output,
+        "--> select the type\n[i] - MIT\n[ii] - GPL\n[iii] - BSD\n>> "
+    );
+
+    let output = test_menu! {
+        menu,
+        "0\n",
+        let license: Type2 = menu.selected(
+            Selected::from("select the type").format(Format::start_index(0)),
+        )?,
+        assert_eq!(license, Type2::MIT),
+    }?;
+    Ok(assert_eq!(
+        output,
+        "--> select the type\n[0] - MIT\n[1] - GPL\n[2] - BSD\n>> "
+    ))
+}
+
+#[test]
+fn theme() -> Res {
+    let mut input = "19\n2\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut menu = Values::from(MenuStream::with(&mut input, &mut output)).theme(Minimal);
+    let age: u8 = menu.written(&Written::from("your age"))?;
+    let license: Type2 = menu.selected(Selected::from("select the type"))?;
+    assert_eq!((age, license), (19, Type2::GPL));
+    drop(menu);
+
+    Ok(assert_eq!(
+        String::from_utf8(output)?,
+        "your age\n> select the type\n1. MIT\n2. GPL\n3. BSD\n> "
+    ))
+}
+
+#[test]
+fn suggest_from() -> Res {
+    let output = test_menu! {
+        menu,
+        "ture\nyes\ntrue\n",
+        let b: bool = menu.written(&Written::from("enable").suggest_from(&["true", "false"]))?,
+        assert!(b),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> enable\n>> did you mean 'true'?\n>> >> "
+    ))
+}
+
+#[test]
+fn strict() -> Res {
+    let output = test_menu! {
+        menu,
+        "2O22\n\n",
+        let year: u16 = menu.written(&Written::from("year").default_value("2022").strict(true))?,
+        assert_eq!(year, 2022),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> year (default: 2022)\n>> '2O22' is not a correct value\n>> "
+    ))
+}
+
+#[test]
+fn wizard() -> Res {
+    let mut input = "a\n1\nb\n2\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut values = Values::from(MenuStream::with(&mut input, &mut output));
+    let names = Wizard::new()
+        .step("first", |values, names: &mut Vec<String>| {
+            names.push(values.written(&Written::from("name"))?);
+            Ok(Flow::Next)
+        })
+        .step("second", |values, _| {
+            let back: u8 = values.written(&Written::from("back"))?;
+            Ok(if back == 1 { Flow::Back } else { Flow::Skip })
+        })
+        .step("skipped", |_, _| panic!("the step should be skipped"))
+        .run(&mut values, Vec::new())?;
+    assert_eq!(names, ["a", "b"]);
+    drop(values);
+
+    Ok(assert_eq!(
+        String::from_utf8(output)?,
+        "Step 1/3: first
+--> name
+>> Step 2/3: second
+--> back
+>> Step 1/3: first
+--> name
+>> Step 2/3: second
+--> back
+>> "
+    ))
+}
+
+#[test]
+fn selected_from_vec() -> Res {
+    let fields: Vec<_> = ["a", "b", "c"].into_iter().zip(1..).collect();
+    assert!(Selected::<_, 2>::from_slice("pick", &fields).is_err());
+
+    let output = test_menu! {
+        menu,
+        "2\n",
+        let n: i32 = menu.selected(Selected::<_, 3>::from_vec("pick", fields)?)?,
+        assert_eq!(n, 2),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> pick\n[1] - a\n[2] - b\n[3] - c\n>> "
+    ))
+}
+
+#[test]
+fn wizard_on_abort() -> Res {
+    let aborted = std::cell::Cell::new(None);
+    let mut input = "a\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut values = Values::from(MenuStream::with(&mut input, &mut output));
+    let res = Wizard::new()
+        .step("first", |values, names: &mut Vec<String>| {
+            names.push(values.written(&Written::from("name"))?);
+            Ok(Flow::Next)
+        })
+        .step("second", |_, _| Err(MenuError::Input))
+        .on_abort(|names, e| aborted.set(Some((names.clone(), e.to_string()))))
+        .run(&mut values, Vec::new());
+
+    assert!(matches!(res, Err(MenuError::Input)));
+    Ok(assert_eq!(
+        aborted.take(),
+        Some((vec!["a".to_owned()], MenuError::Input.to_string()))
+    ))
+}
+
+#[test]
+fn wizard_resume() -> Res {
+    let mut wizard = Wizard::new()
+        .step("first", |values, nums: &mut Vec<u8>| {
+            nums.push(values.written(&Written::from("first"))?);
+            Ok(Flow::Next)
+        })
+        .step("second", |values, nums| {
+            let n: u8 = values.written(&Written::from("second"))?;
+            if n == 0 {
+                return Err(MenuError::Input);
+            }
+            nums.push(n);
+            Ok(Flow::Next)
+        });
+
+    let mut input = "1\n0\n2\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut values = Values::from(MenuStream::with(&mut input, &mut output));
+    let aborted = match wizard.try_run(&mut values, Vec::new()) {
+        Err(aborted) => aborted,
+        Ok(nums) => panic!("the wizard should fail, got {:?}", nums),
+    };
+    assert_eq!((&aborted.state, aborted.step), (&vec![1], 1));
+
+    let nums = wizard.try_run_from(&mut values, aborted.state, aborted.step);
+    assert_eq!(nums.ok(), Some(vec![1, 2]));
+    drop(values);
+
+    Ok(assert_eq!(
+        String::from_utf8(output)?,
+        "Step 1/2: first
+--> first
+>> Step 2/2: second
+--> second
+>> Step 2/2: second
+--> second
+>> "
+    ))
+}
+
+#[test]
+fn show_answers() -> Res {
+    let mut input = "19\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut menu = Values::from(MenuStream::with(&mut input, &mut output))
+        .with_answers(HashMap::from([("name".to_owned(), "Ahmad".to_owned())]))
+        .show_answers(true);
+    let name: String = menu.written(&Written::from("name"))?;
+    let age: u8 = menu.written(&Written::from("age"))?;
+    assert_eq!((name.as_str(), age), ("Ahmad", 19));
+    drop(menu);
+
+    Ok(assert_eq!(
+        String::from_utf8(output)?,
+        "--> name: Ahmad\n--> age\n>> "
+    ))
+}
+
+#[test]
+fn verbosity() -> Res {
+    let field = Written::from("your age").example("19").default_value("18");
+    let mut input = "\n\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut menu =
+        Values::from(MenuStream::with(&mut input, &mut output)).verbosity(Verbosity::Minimal);
+    let age: u8 = menu.written(&field)?;
+    assert_eq!(age, 18);
+    drop(menu);
+    assert_eq!(String::from_utf8(output)?, "--> your age\n>> ");
+
+    let mut input = "\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut menu =
+        Values::from(MenuStream::with(&mut input, &mut output)).verbosity(Verbosity::Verbose);
+    let age: u8 = menu.written(&field)?;
+    assert_eq!(age, 18);
+    drop(menu);
+
+    Ok(assert_eq!(
+        String::from_utf8(output)?,
+        "--> your age (example: 19, default: 18)\nPress Enter to use the default value.\n>> "
+    ))
+}
+
+#[test]
+fn selected_from_iter() -> Res {
+    let output = test_menu! {
+        menu,
+        "3\n",
+        let port: u16 = menu.selected(Selected::from_iter("select a port", vec![80, 443, 8080]))?,
+        assert_eq!(port, 8080),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> select a port\n[1] - 80\n[2] - 443\n[3] - 8080\n>> "
+    ))
+}
+
+#[test]
+fn spinner() -> Res {
+    let output = test_menu! {
+        menu,
+        "abc\n70000\n443\n\n",
+        let spinner = Spinner::new("port", 1..=65535).default_value(8080),
+        assert_eq!(menu.spinner(&spinner)?, 443),
+        assert_eq!(menu.spinner(&spinner)?, 8080),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> port (from 1 to 65535, default: 8080)\n>> >> >> \
+        --> port (from 1 to 65535, default: 8080)\n>> "
+    ))
+}
+
+#[test]
+fn typed_default_mismatch() -> Res {
+    let output = test_menu! {
+        menu,
+        "\n",
+        let field = Written::from("port").default_typed(8080u16),
+        // The default value is never parsed as a value of another type.
+        assert_eq!(menu.written::<u32>(&field).map_err(|e| e.kind()), Err(MenuErrorKind::Other)),
+        assert_eq!(menu.written::<u16>(&field)?, 8080),
+    }?;
+
+    Ok(assert_eq!(output, "--> port (default: 8080)\n>> "))
+}
+
+#[cfg(feature = "crossterm")]
+#[test]
+fn written_timeout() -> Res {
+    use std::time::Duration;
+
+    // The timeout has already elapsed, so the input is not read.
+    let mut input = "".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut stream = MenuStream::with(&mut input, &mut output).arrows(true);
+    let field = Written::from("entry").timeout(Duration::ZERO);
+    assert_eq!(
+        field
+            .prompt::<_, _, String>(&mut stream)
+            .map_err(|e| e.kind()),
+        Err(MenuErrorKind::Timeout)
+    );
+    let entry: String = field.default_value("linux").prompt(&mut stream)?;
+    assert_eq!(entry, "linux");
+    drop(stream);
+
+    Ok(assert_eq!(
+        String::from_utf8(output)?,
+        "--> entry\n>> \n--> entry (default: linux)\n>> \n"
+    ))
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn inverted_spinner() -> Res {
+    let output = test_menu! {
+        menu,
+        "5\n",
+        let spinner = Spinner::new("count", 10..=1).default_value(20),
+        assert_eq!(menu.spinner(&spinner)?, 5),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> count (from 1 to 10, default: 10)\n>> "
+    ))
+}
+
+#[test]
+fn table() -> Res {
+    let rows: Vec<Vec<String>> = (1..=10)
+        .map(|i| vec![format!("web-{}", i), format!("10.0.0.{}", i)])
+        .collect();
+    let output = test_menu! {
+        menu,
+        "0\n10\n",
+        let row = menu.table(&TableSelect::new("select a server", &["NAME", "IP"], rows).format(Format {
+            page_size: Some(2),
+            ..Default::default()
+        }))?,
+        assert_eq!(row, 9),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> select a server\n       NAME    IP\n[1]  - web-1   10.0.0.1\n\
+        [2]  - web-2   10.0.0.2\n[n] - next page\n>> >> "
+    ))
+}
+
+#[test]
+fn cancelled() {
+    let mut input = "".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut menu = Values::from(MenuStream::with(&mut input, &mut output));
+    assert_eq!(
+        menu.written::<u8>(&Written::from("your age")),
+        Err(MenuError::Cancelled)
+    );
+    assert_eq!(
+        menu.selected::<Type2, 3>(Selected::from("select the type")),
+        Err(MenuError::Cancelled)
+    );
+}
+
+#[test]
+fn max_attempts() -> Res {
+    let mut input = "a\nb\n4\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut menu = Values::from(MenuStream::with(&mut input, &mut output));
+    assert_eq!(
+        menu.written::<u8>(&Written::from("your age").max_attempts(2)),
+        Err(MenuError::TooManyAttempts)
+    );
+    assert_eq!(
+        menu.selected::<Type2, 3>(Selected::from("select the type").max_attempts(1)),
+        Err(MenuError::TooManyAttempts)
+    );
+    drop(menu);
+
+    Ok(assert_eq!(
+        String::from_utf8(output)?,
+        "--> your age\n>> >> --> select the type\n[1] - MIT\n[2] - GPL\n[3] - BSD\n>> "
+    ))
+}
+
+#[test]
+fn backoff() {
+    let mut input = "a\nb\n4\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut menu = Values::from(MenuStream::with(&mut input, &mut output));
+    let start = Instant::now();
+    assert_eq!(
+        menu.written::<u8>(&Written::from("your age").backoff(Duration::from_millis(10))),
+        Ok(4)
+    );
+    // The delay doubles after the second incorrect input.
+    assert!(start.elapsed() >= Duration::from_millis(30));
+}
+
+#[cfg(feature = "keyring")]
+#[test]
+fn secret() -> Res {
+    // The mock store never contains the secret, so it is prompted.
+    keyring::set_default_credential_builder(keyring::mock::default_credential_builder());
+    let output = test_menu! {
+        menu,
+        "hunter2\nno\n",
+        let token = menu.secret(&Secret::new(Written::from("token"), "ezmenulib", "test").offer_store(true))?,
+        assert_eq!(token, "hunter2"),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> token\n>> --> Save it to the credential store? (default: no)\n>> "
+    ))
+}
+
+#[test]
+fn file_picker() -> Res {
+    let dir = std::env::temp_dir().join(format!("ezmenulib_file_picker_{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("sub"))?;
+    for file in ["sub/a.txt", "sub/b.rs", "c.txt"] {
+        std::fs::write(dir.join(file), "")?;
+    }
+
+    let output = test_menu! {
+        menu,
+        "2\n2\n",
+        let path = menu.file(&FilePicker::from("select a file").dir(&dir).extensions(&["txt"]))?,
+        assert_eq!(path, dir.canonicalize()?.join("sub").join("a.txt")),
+    }?;
+
+    std::fs::remove_dir_all(&dir)?;
+    assert!(output.contains("[2] - sub/\n[3] - c.txt\n"));
+    assert!(output.contains("[1] - ../\n[2] - a.txt\n>> "));
+    Ok(assert!(!output.contains("b.rs")))
+}
+
+#[cfg(unix)]
+#[test]
+fn file_picker_unreadable() -> Res {
+    use std::{fs, os::unix::fs::PermissionsExt};
+
+    let dir = std::env::temp_dir().join(format!(
+        "ezmenulib_file_picker_unreadable_{}",
+        std::process::id()
+    ));
+    let locked = dir.join("locked");
+    fs::create_dir_all(&locked)?;
+    fs::write(dir.join("c.txt"), "")?;
+    fs::set_permissions(&locked, fs::Permissions::from_mode(0o000))?;
+    // The permissions are ignored by a privileged user.
+    let output = match fs::read_dir(&locked) {
+        Ok(_) => None,
+        Err(_) => Some(test_menu! {
+            menu,
+            "2\n3\n",
+            let path = menu.file(&FilePicker::from("select a file").dir(&dir)),
+            assert_eq!(path, Ok(dir.canonicalize()?.join("c.txt"))),
+        }?),
+    };
+
+    fs::set_permissions(&locked, fs::Permissions::from_mode(0o755))?;
+    fs::remove_dir_all(&dir)?;
+    let output = match output {
+        Some(output) => output,
+        None => return Ok(()),
+    };
+    // The picker stays in the same directory after the error.
+    assert!(output.contains(">> locked/: "));
+    Ok(assert_eq!(output.matches("[3] - c.txt").count(), 1))
+}
+
+#[test]
+fn docs() -> Res {
+    let output = test_menu! {
+        menu,
+        "?!\n4\n",
+        let n: u8 = menu.written(&Written::from("workers").docs("https://example.com/workers"))?,
+        assert_eq!(n, 4),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> workers\n>> Documentation: https://example.com/workers\n>> "
+    ))
+}
@@ -0,0 +1,119 @@
+use crate::MenuResult;
+use std::collections::HashMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+/// Counts how many times the fields of a [`RawMenu`](crate::menu::RawMenu) are selected.
+///
+/// The counters can be persisted in a file, for example in the home directory of the user,
+/// to sort the most used fields at the top of the menus, or to display the counters
+/// next to the fields. This way, the frequently used fields of deep menus become
+/// faster to reach over time.
+///
+/// A field is identified by the messages of its parent menus and its own message,
+/// separated by `/`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use ezmenulib::prelude::*;
+/// # fn main() -> MenuResult {
+/// RawMenu::from(&[("Deploy", Kind::Quit), ("Rollback", Kind::Quit)])
+///     .usage(Usage::load(".menu_usage")?.sorted(true))
+///     .run()?;
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Usage {
+    counts: HashMap<String, u64>,
+    path: Option<PathBuf>,
+    sorted: bool,
+    annotated: bool,
+}
+
+impl Usage {
+    /// Returns the counters stored in memory, starting from zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads the counters from the file at the given path.
+    ///
+    /// If the file does not exist, the counters start from zero. The file is then written
+    /// each time a field is selected.
+    pub fn load<P: Into<PathBuf>>(path: P) -> MenuResult<Self> {
+        let path = path.into();
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        let counts = content
+            .lines()
+            .filter_map(|line| {
+                let (count, key) = line.split_once('\t')?;
+                Some((key.to_owned(), count.parse().ok()?))
+            })
+            .collect();
+
+        Ok(Self {
+            counts,
+            path: Some(path),
+            ..Default::default()
+        })
+    }
+
+    /// Defines if the most used fields are displayed at the top of the menus
+    /// (`false` by default).
+    pub fn sorted(mut self, sorted: bool) -> Self {
+        self.sorted = sorted;
+        self
+    }
+
+    /// Defines if the counters are displayed next to the fields (`false` by default).
+    pub fn annotated(mut self, annotated: bool) -> Self {
+        self.annotated = annotated;
+        self
+    }
+
+    /// Returns how many times the field identified by the given key has been selected.
+    pub fn count(&self, key: &str) -> u64 {
+        self.counts.get(key).copied().unwrap_or_default()
+    }
+
+    /// Writes the counters to the file they have been loaded from, if any.
+    pub fn save(&self) -> MenuResult {
+        if let Some(ref path) = self.path {
+            let content: String = self
+                .counts
+                .iter()
+                .map(|(key, count)| format!("{}\t{}\n", count, key))
+                .collect();
+            fs::write(path, content)?;
+        }
+        Ok(())
+    }
+
+    /// Increments the counter of the field identified by the given key, then saves the counters.
+    pub(crate) fn record(&mut self, key: String) -> MenuResult {
+        *self.counts.entry(key).or_default() += 1;
+        self.save()
+    }
+
+    /// Returns the indexes of the fields in the order they are displayed.
+    pub(crate) fn order<'k>(&self, keys: impl Iterator<Item = &'k str>) -> Vec<usize> {
+        let counts: Vec<_> = keys.map(|key| self.count(key)).collect();
+        let mut order: Vec<usize> = (0..counts.len()).collect();
+        if self.sorted {
+            // The sort is stable, so the fields used equally keep their order.
+            order.sort_by(|a, b| counts[*b].cmp(&counts[*a]));
+        }
+        order
+    }
+
+    pub(crate) fn is_annotated(&self) -> bool {
+        self.annotated
+    }
+}
@@ -0,0 +1,188 @@
+use crate::prelude::*;
+use std::fmt;
+use std::io::{BufRead, Write};
+
+/// The navigation returned by a step of a [`Wizard`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flow {
+    /// Goes to the next step.
+    Next,
+    /// Goes back to the previous step, to change its answers.
+    Back,
+    /// Skips the next step.
+    Skip,
+}
+
+/// A step of the wizard, retrieving values into the combined result.
+type Step<'a, S, R, W> = Box<dyn FnMut(&mut Values<R, W>, &mut S) -> MenuResult<Flow> + 'a>;
+
+/// The hook called with the partially-collected result when the wizard fails.
+type Abort<'a, S> = Box<dyn FnMut(&mut S, &MenuError) + 'a>;
+
+/// A sequence of named steps, retrieving the values of a multi-step form.
+///
+/// Each step is a function using the [`Values`] container to prompt its fields,
+/// and storing the values into the combined result, of type `S`. The previous answers
+/// are then available to the next steps, e.g. to skip a step depending on them.
+///
+/// Before each step, the wizard displays its progress, like `Step 2/3: Network settings`.
+/// The step returns the [flow](Flow) of the wizard, so it can go back to the previous step
+/// or skip the next one.
+///
+/// # Example
+///
+/// ```no_run
+/// # use ezmenulib::{customs::MenuBool, prelude::*};
+/// # fn main() -> MenuResult {
+/// #[derive(Default)]
+/// struct Config {
+///     name: String,
+///     email: bool,
+///     smtp_port: u16,
+/// }
+///
+/// let config = Wizard::new()
+///     .step("Profile", |values, config: &mut Config| {
+///         config.name = values.written(&Written::from("Name"))?;
+///         config.email = values.written::<MenuBool>(&Written::from("Use email?"))?.0;
+///         Ok(if config.email { Flow::Next } else { Flow::Skip })
+///     })
+///     .step("Email settings", |values, config| {
+///         config.smtp_port = values.written(&Written::from("SMTP port").default_value("587"))?;
+///         Ok(Flow::Next)
+///     })
+///     .run(&mut Values::default(), Config::default())?;
+/// # Ok(()) }
+/// ```
+pub struct Wizard<'a, S, R = In, W = Out> {
+    steps: Vec<(&'a str, Step<'a, S, R, W>)>,
+    on_abort: Option<Abort<'a, S>>,
+}
+
+impl<S, R, W> fmt::Debug for Wizard<'_, S, R, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Wizard")
+            .field(
+                "steps",
+                &self.steps.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+            )
+            .field("on_abort", &self.on_abort.is_some())
+            .finish()
+    }
+}
+
+impl<S, R, W> Default for Wizard<'_, S, R, W> {
+    fn default() -> Self {
+        Self {
+            steps: Vec::new(),
+            on_abort: None,
+        }
+    }
+}
+
+impl<'a, S, R: BufRead, W: Write> Wizard<'a, S, R, W> {
+    /// Returns the wizard without any step.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a step with the given name to the wizard.
+    pub fn step<F>(mut self, name: &'a str, f: F) -> Self
+    where
+        F: FnMut(&mut Values<R, W>, &mut S) -> MenuResult<Flow> + 'a,
+    {
+        self.steps.push((name, Box::new(f)));
+        self
+    }
+
+    /// Defines the hook called when a step returns an error, e.g. an IO error.
+    ///
+    /// The hook is called with the partially-collected result and the error, before the wizard
+    /// returns the error. It is useful to clean up the resources created by the previous steps.
+    pub fn on_abort<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(&mut S, &MenuError) + 'a,
+    {
+        self.on_abort = Some(Box::new(f));
+        self
+    }
+
+    /// Runs the steps of the wizard, then returns the combined result.
+    ///
+    /// The result starts from the given initial value. Going back from the first step
+    /// runs it again, and skipping the last step ends the wizard.
+    ///
+    /// If a step returns an error, the abort hook is called, if any (see [`Wizard::on_abort`]),
+    /// then the error is returned.
+    pub fn run(&mut self, values: &mut Values<R, W>, state: S) -> MenuResult<S> {
+        self.try_run(values, state).map_err(|aborted| aborted.error)
+    }
+
+    /// Runs the steps of the wizard, then returns the combined result, or the partial result
+    /// if a step returns an error.
+    ///
+    /// It behaves like the [`Wizard::run`] function, but the answers already collected
+    /// are not lost on error. The caller may then persist them, and resume the wizard later
+    /// at the failed step, with the [`Wizard::try_run_from`] function.
+    pub fn try_run(&mut self, values: &mut Values<R, W>, state: S) -> Result<S, Aborted<S>> {
+        self.try_run_from(values, state, 0)
+    }
+
+    /// Runs the steps of the wizard from the step at the given index, then returns
+    /// the combined result, or the partial result if a step returns an error.
+    ///
+    /// See [`Wizard::try_run`] for more information.
+    pub fn try_run_from(
+        &mut self,
+        values: &mut Values<R, W>,
+        mut state: S,
+        step: usize,
+    ) -> Result<S, Aborted<S>> {
+        let len = self.steps.len();
+        let mut i = step;
+        while i < len {
+            match self.run_step(values, &mut state, i, len) {
+                Ok(Flow::Next) => i += 1,
+                Ok(Flow::Back) => i = i.saturating_sub(1),
+                Ok(Flow::Skip) => i += 2,
+                Err(error) => {
+                    if let Some(ref mut f) = self.on_abort {
+                        f(&mut state, &error);
+                    }
+                    return Err(Aborted {
+                        state,
+                        step: i,
+                        error,
+                    });
+                }
+            }
+        }
+        Ok(state)
+    }
+
+    /// Displays the progress of the wizard, then runs the step at the given index.
+    fn run_step(
+        &mut self,
+        values: &mut Values<R, W>,
+        state: &mut S,
+        i: usize,
+        len: usize,
+    ) -> MenuResult<Flow> {
+        let (name, f) = &mut self.steps[i];
+        writeln!(values.get_mut_object(), "Step {}/{}: {}", i + 1, len, name)?;
+        f(values, state)
+    }
+}
+
+/// The partial result of a [`Wizard`] whose step returned an error.
+///
+/// See [`Wizard::try_run`] for more information.
+#[derive(Debug)]
+pub struct Aborted<S> {
+    /// The partially-collected result.
+    pub state: S,
+    /// The index of the step that returned the error.
+    pub step: usize,
+    /// The error returned by the step.
+    pub error: MenuError,
+}
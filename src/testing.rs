@@ -0,0 +1,87 @@
+//! Module providing helpers to test the menus with scripted inputs.
+//!
+//! Instead of wrapping a reader and a writer into a [`MenuStream`] for each test,
+//! the [`ScriptedStream`] feeds the given answers to the prompts, and captures the output
+//! to check it with the [`assert_prompted`](crate::assert_prompted) macro.
+
+use crate::menu::MenuStream;
+use std::io::Cursor;
+
+/// A stream feeding canned answers to the prompts, and capturing their output.
+///
+/// # Example
+///
+/// ```
+/// use ezmenulib::{assert_prompted, prelude::*, testing::ScriptedStream};
+///
+/// # fn main() -> MenuResult {
+/// let mut script = ScriptedStream::new(["Bob", "19"]);
+/// let mut values = Values::from(script.stream());
+/// let name: String = values.written(&Written::from("Name"))?;
+/// let age: u8 = values.written(&Written::from("Age"))?;
+/// drop(values);
+///
+/// assert_eq!((name.as_str(), age), ("Bob", 19));
+/// assert!(script.is_consumed());
+/// assert_prompted!(script, "--> Age\n>> ");
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Default)]
+pub struct ScriptedStream {
+    input: Cursor<Vec<u8>>,
+    output: Vec<u8>,
+}
+
+impl ScriptedStream {
+    /// Returns the stream feeding the given answers, one per line.
+    pub fn new<I, S>(answers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut input = String::new();
+        for answer in answers {
+            input.push_str(answer.as_ref());
+            input.push('\n');
+        }
+
+        Self {
+            input: Cursor::new(input.into_bytes()),
+            output: Vec::new(),
+        }
+    }
+
+    /// Returns the menu stream reading the answers and writing to the captured output.
+    ///
+    /// The answers already read by a previous stream are not fed again.
+    pub fn stream(&mut self) -> MenuStream<'_, Cursor<Vec<u8>>, Vec<u8>> {
+        MenuStream::with(&mut self.input, &mut self.output)
+    }
+
+    /// Returns the output captured so far.
+    pub fn output(&self) -> String {
+        String::from_utf8_lossy(&self.output).into_owned()
+    }
+
+    /// Returns true if all the answers have been read.
+    pub fn is_consumed(&self) -> bool {
+        self.input.position() as usize == self.input.get_ref().len()
+    }
+}
+
+/// Asserts that the output captured by a [`ScriptedStream`](crate::testing::ScriptedStream)
+/// contains the given text.
+///
+/// See [`ScriptedStream`](crate::testing::ScriptedStream) for an example.
+#[macro_export]
+macro_rules! assert_prompted {
+    ($script:expr, $text:expr $(,)?) => {{
+        let output = $script.output();
+        assert!(
+            output.contains($text),
+            "expected the output to contain {:?}, got {:?}",
+            $text,
+            output,
+        );
+    }};
+}
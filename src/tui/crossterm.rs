@@ -39,11 +39,10 @@ impl From<CTEvent> for Event {
                 KeyCode::Delete => Key(Delete),
                 KeyCode::Insert => Key(Insert),
                 KeyCode::F(x) => Key(F(x)),
-                KeyCode::Char(c) => match modifiers {
-                    KeyModifiers::ALT => Key(Alt(c)),
-                    KeyModifiers::CONTROL => Key(Ctrl(c)),
-                    _ => Key(Char(c)),
-                },
+                // The shift modifier is already applied to the char.
+                KeyCode::Char(c) if modifiers.contains(KeyModifiers::CONTROL) => Key(Ctrl(c)),
+                KeyCode::Char(c) if modifiers.contains(KeyModifiers::ALT) => Key(Alt(c)),
+                KeyCode::Char(c) => Key(Char(c)),
                 KeyCode::Null => Key(Null),
                 KeyCode::Esc => Key(Esc),
             },
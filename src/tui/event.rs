@@ -1,6 +1,15 @@
 //! Module defining the event types used by the library.
 //!
 //! It is a merged version between `crossterm` and `termion` event types.
+//!
+//! Each backend converts its own events into these types, so the menu navigation
+//! is handled the same way whatever the backend is.
+
+use std::fmt;
+
+use tui::layout::Rect;
+
+use super::MenuLayout;
 
 /// The event type representing the merge between `crossterm` and `termion` event type.
 ///
@@ -17,7 +26,7 @@ pub enum Event {
 }
 
 /// The key event type representing the merge between `crossterm` and `termion` key event type.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum KeyEvent {
     /// Backspace.
     Backspace,
@@ -61,6 +70,35 @@ pub enum KeyEvent {
     Ctrl(char),
 }
 
+impl fmt::Display for KeyEvent {
+    /// Displays the name of the key, e.g. `↑`, `Enter` or `Ctrl-C`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Backspace => f.write_str("Backspace"),
+            Self::Enter => f.write_str("Enter"),
+            Self::Left => f.write_str("←"),
+            Self::Right => f.write_str("→"),
+            Self::Up => f.write_str("↑"),
+            Self::Down => f.write_str("↓"),
+            Self::Home => f.write_str("Home"),
+            Self::End => f.write_str("End"),
+            Self::PageUp => f.write_str("PageUp"),
+            Self::PageDown => f.write_str("PageDown"),
+            Self::Tab => f.write_str("Tab"),
+            Self::BackTab => f.write_str("Shift-Tab"),
+            Self::Delete => f.write_str("Delete"),
+            Self::Insert => f.write_str("Insert"),
+            Self::F(n) => write!(f, "F{}", n),
+            Self::Char(' ') => f.write_str("Space"),
+            Self::Char(c) => write!(f, "{}", c),
+            Self::Null => f.write_str("Null"),
+            Self::Esc => f.write_str("Esc"),
+            Self::Alt(c) => write!(f, "Alt-{}", c.to_ascii_uppercase()),
+            Self::Ctrl(c) => write!(f, "Ctrl-{}", c.to_ascii_uppercase()),
+        }
+    }
+}
+
 /// The mouse buttons.
 #[derive(Debug, Clone, Copy)]
 pub enum MouseButton {
@@ -72,7 +110,9 @@ pub enum MouseButton {
     Middle,
 }
 
-/// The mouse event type representing the merge between `crossterm` and `termion` ket event type.
+/// The mouse event type representing the merge between `crossterm` and `termion` key event type.
+///
+/// The positions are the zero-based column and row of the cursor.
 #[derive(Debug, Clone, Copy)]
 pub enum MouseEvent {
     /// A mouse button has just been pressed down.
@@ -84,3 +124,269 @@ pub enum MouseEvent {
     /// The scroll down event.
     ScrollDown,
 }
+
+/// Defines the keys navigating through the pages of a [`TuiMenu`](crate::tui::TuiMenu).
+///
+/// By default, `q`, `Ctrl-C` and `Ctrl-D` close all the nested menu pages,
+/// and `Esc` goes back to the previous page. These keys take precedence over
+/// the others. The arrows, `Tab` and `Shift-Tab` move the selection, `Home` and `End`
+/// move it to the first or the last field, and `Enter` or `Space` selects the field.
+///
+/// The up and down keys move the selection to the field above or below, according to
+/// the [layout](MenuLayout) of the menu, and the previous and next keys move it
+/// to the previous or the next field.
+///
+/// # Example
+///
+/// ```
+/// use ezmenulib::tui::event::{KeyEvent, KeyMap};
+///
+/// // Only `Esc` closes the menu, so `q` can't be pressed by mistake.
+/// let keys = KeyMap::new().quit_keys([KeyEvent::Esc]).back_keys([KeyEvent::Backspace]);
+///
+/// // The selection is moved with `j` and `k`, along with the arrows.
+/// let keys = KeyMap::new().vim();
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeyMap {
+    quit: Vec<KeyEvent>,
+    back: Vec<KeyEvent>,
+    up: Vec<KeyEvent>,
+    down: Vec<KeyEvent>,
+    prev: Vec<KeyEvent>,
+    next: Vec<KeyEvent>,
+    first: Vec<KeyEvent>,
+    last: Vec<KeyEvent>,
+    select: Vec<KeyEvent>,
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        use KeyEvent::*;
+
+        Self {
+            quit: vec![Char('q'), Ctrl('c'), Ctrl('d')],
+            back: vec![Esc],
+            up: vec![Up],
+            down: vec![Down],
+            prev: vec![Left, BackTab],
+            next: vec![Right, Tab],
+            first: vec![Home],
+            last: vec![End],
+            select: vec![Enter, Char(' ')],
+        }
+    }
+}
+
+impl KeyMap {
+    /// Returns the default keys.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Defines the keys closing all the nested menu pages.
+    pub fn quit_keys<I: IntoIterator<Item = KeyEvent>>(mut self, keys: I) -> Self {
+        self.quit = keys.into_iter().collect();
+        self
+    }
+
+    /// Defines the keys going back to the previous menu page.
+    pub fn back_keys<I: IntoIterator<Item = KeyEvent>>(mut self, keys: I) -> Self {
+        self.back = keys.into_iter().collect();
+        self
+    }
+
+    /// Defines the keys moving the selection to the field above.
+    pub fn up_keys<I: IntoIterator<Item = KeyEvent>>(mut self, keys: I) -> Self {
+        self.up = keys.into_iter().collect();
+        self
+    }
+
+    /// Defines the keys moving the selection to the field below.
+    pub fn down_keys<I: IntoIterator<Item = KeyEvent>>(mut self, keys: I) -> Self {
+        self.down = keys.into_iter().collect();
+        self
+    }
+
+    /// Defines the keys moving the selection to the previous field.
+    pub fn prev_keys<I: IntoIterator<Item = KeyEvent>>(mut self, keys: I) -> Self {
+        self.prev = keys.into_iter().collect();
+        self
+    }
+
+    /// Defines the keys moving the selection to the next field.
+    pub fn next_keys<I: IntoIterator<Item = KeyEvent>>(mut self, keys: I) -> Self {
+        self.next = keys.into_iter().collect();
+        self
+    }
+
+    /// Defines the keys moving the selection to the first field.
+    pub fn first_keys<I: IntoIterator<Item = KeyEvent>>(mut self, keys: I) -> Self {
+        self.first = keys.into_iter().collect();
+        self
+    }
+
+    /// Defines the keys moving the selection to the last field.
+    pub fn last_keys<I: IntoIterator<Item = KeyEvent>>(mut self, keys: I) -> Self {
+        self.last = keys.into_iter().collect();
+        self
+    }
+
+    /// Defines the keys selecting the current field.
+    pub fn select_keys<I: IntoIterator<Item = KeyEvent>>(mut self, keys: I) -> Self {
+        self.select = keys.into_iter().collect();
+        self
+    }
+
+    /// Adds the vim-style keys to the current ones.
+    ///
+    /// `k` and `j` move the selection up and down, `h` and `l` to the previous
+    /// and the next field, `g` and `G` to the first and the last field.
+    pub fn vim(mut self) -> Self {
+        use KeyEvent::Char;
+
+        self.up.push(Char('k'));
+        self.down.push(Char('j'));
+        self.prev.push(Char('h'));
+        self.next.push(Char('l'));
+        self.first.push(Char('g'));
+        self.last.push(Char('G'));
+        self
+    }
+
+    /// Returns the hints of the keys, like `↑/↓ move · Enter select · Esc back · q quit`.
+    ///
+    /// Only the first key of each action is displayed, and the actions without any key
+    /// are skipped. The keys moving the selection depend on the layout of the menu.
+    pub(crate) fn hints(&self, layout: MenuLayout) -> String {
+        let moves: &[&Vec<KeyEvent>] = match layout {
+            MenuLayout::Vertical => &[&self.up, &self.down],
+            MenuLayout::Horizontal => &[&self.prev, &self.next],
+            MenuLayout::Grid { .. } => &[&self.up, &self.down, &self.prev, &self.next],
+        };
+        let moves: Vec<String> = moves
+            .iter()
+            .filter_map(|keys| keys.first().map(KeyEvent::to_string))
+            .collect();
+
+        let mut hints = Vec::new();
+        if !moves.is_empty() {
+            hints.push(format!("{} move", moves.join("/")));
+        }
+        for (keys, action) in [
+            (&self.select, "select"),
+            (&self.back, "back"),
+            (&self.quit, "quit"),
+        ] {
+            if let Some(key) = keys.first() {
+                hints.push(format!("{} {}", key, action));
+            }
+        }
+        hints.join(" · ")
+    }
+
+    /// Disables the keys closing all the nested menu pages.
+    ///
+    /// The menu can then only be closed by selecting a [`TuiKind::Quit`](crate::tui::TuiKind::Quit)
+    /// field.
+    pub fn no_quit(self) -> Self {
+        self.quit_keys([])
+    }
+}
+
+/// The action performed on the menu by a key pressed by the user.
+///
+/// This is the backend-agnostic core of the tui menus navigation: the backends
+/// only convert their events into a [`KeyEvent`], and the latter is then mapped to an action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Action {
+    /// Moves the selection to the field above.
+    Up,
+    /// Moves the selection to the field below.
+    Down,
+    /// Moves the selection to the previous field.
+    Prev,
+    /// Moves the selection to the next field.
+    Next,
+    /// Moves the selection to the first field.
+    First,
+    /// Moves the selection to the last field.
+    Last,
+    /// Selects the current field.
+    Select,
+    /// Selects the field at the given index.
+    SelectAt(usize),
+    /// Goes back to the previous menu page.
+    Back,
+    /// Closes all the nested menu pages.
+    Quit,
+}
+
+impl Action {
+    /// Returns the action corresponding to the given key, if there is any.
+    ///
+    /// The keys closing the menu pages take precedence over the other keys of the key map.
+    pub(crate) fn from_key(k: KeyEvent, keys: &KeyMap) -> Option<Self> {
+        [
+            (&keys.quit, Self::Quit),
+            (&keys.back, Self::Back),
+            (&keys.up, Self::Up),
+            (&keys.down, Self::Down),
+            (&keys.prev, Self::Prev),
+            (&keys.next, Self::Next),
+            (&keys.first, Self::First),
+            (&keys.last, Self::Last),
+            (&keys.select, Self::Select),
+        ]
+        .into_iter()
+        .find_map(|(bound, action)| bound.contains(&k).then_some(action))
+    }
+
+    /// Returns the action closing a popup corresponding to the given key, if there is any.
+    ///
+    /// Only the quit and back keys of the key map close a popup. The characters are typed
+    /// in the popup instead, even if they are bound to an action.
+    pub(crate) fn from_popup_key(k: KeyEvent, keys: &KeyMap) -> Option<Self> {
+        if matches!(k, KeyEvent::Char(_)) {
+            return None;
+        }
+        [(&keys.quit, Self::Quit), (&keys.back, Self::Back)]
+            .into_iter()
+            .find_map(|(bound, action)| bound.contains(&k).then_some(action))
+    }
+
+    /// Returns the action corresponding to the given mouse event, if there is any.
+    ///
+    /// The area is the one the menu is drawn in, with the given amount of fields displayed
+    /// in the given amount of columns. A left click selects the field under the cursor,
+    /// and scrolling moves the selection.
+    pub(crate) fn from_mouse(
+        m: MouseEvent,
+        area: Rect,
+        len: usize,
+        columns: usize,
+    ) -> Option<Self> {
+        match m {
+            MouseEvent::Down(MouseButton::Left, x, y) => {
+                // The fields are drawn inside the borders of the block, in rows of cells.
+                let inside = x > area.x && x < area.right().saturating_sub(1);
+                let row = usize::from(y.checked_sub(area.y + 1)?);
+                let cell = cell_width(area.width, columns);
+                let column = usize::from(x.checked_sub(area.x + 1)? / cell);
+                let i = row * columns + column;
+                (inside && y < area.bottom().saturating_sub(1) && column < columns && i < len)
+                    .then_some(Self::SelectAt(i))
+            }
+            MouseEvent::ScrollUp => Some(Self::Prev),
+            MouseEvent::ScrollDown => Some(Self::Next),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the width of the cell of a field, inside the borders of a block
+/// of the given width.
+pub(crate) fn cell_width(width: u16, columns: usize) -> u16 {
+    let columns = u16::try_from(columns).unwrap_or(u16::MAX).max(1);
+    (width.saturating_sub(2) / columns).max(1)
+}
@@ -0,0 +1,50 @@
+//! Module defining the file pickers displayed inside the tui menus.
+
+use std::{fmt, path::PathBuf};
+
+use tui::{backend::Backend, Terminal};
+
+use crate::{field::FilePicker, MenuResult};
+
+/// The function called with the path of the file selected by the user.
+type Handler<'a, B> = Box<dyn Fn(&mut Terminal<B>, PathBuf) -> MenuResult + 'a>;
+
+/// A file picker displayed inside a tui menu.
+///
+/// Each directory is displayed as a sub-menu level listing its entries. Selecting
+/// a directory opens it, and going back displays the parent directory, until the directory
+/// listed first is closed. When the user selects a file, its absolute path is passed
+/// to the function.
+///
+/// See [`TuiKind::Files`](crate::tui::TuiKind::Files) for more information.
+pub struct TuiFilePicker<'a, B: Backend> {
+    pub(crate) picker: &'a FilePicker<'a>,
+    handler: Handler<'a, B>,
+}
+
+impl<B: Backend> fmt::Debug for TuiFilePicker<'_, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TuiFilePicker")
+            .field("picker", self.picker)
+            .finish()
+    }
+}
+
+impl<'a, B: Backend> TuiFilePicker<'a, B> {
+    /// Returns the tui version of the file picker, calling the given function
+    /// with the path of the selected file.
+    pub fn new<F>(picker: &'a FilePicker<'a>, f: F) -> Self
+    where
+        F: Fn(&mut Terminal<B>, PathBuf) -> MenuResult + 'a,
+    {
+        Self {
+            picker,
+            handler: Box::new(f),
+        }
+    }
+
+    /// Calls the function with the path of the file selected by the user.
+    pub(crate) fn submit(&self, term: &mut Terminal<B>, path: PathBuf) -> MenuResult {
+        (self.handler)(term, path)
+    }
+}
@@ -3,9 +3,17 @@
 //! This module is mainly used to generate menu using the [`tui`](https://docs.rs/tui/) crate.
 
 pub mod event;
+mod files;
+mod prompt;
+
+#[cfg(test)]
+mod tests;
 
 use std::{
-    fmt, io,
+    collections::{HashMap, VecDeque},
+    fmt,
+    io::{self, Write},
+    mem,
     ops::{Deref, DerefMut},
 };
 
@@ -14,17 +22,25 @@ use tui::{
     buffer::Buffer,
     layout::{Alignment, Rect},
     style::{Color, Modifier, Style},
-    widgets::{Block, Borders, Widget},
+    widgets::{Block, Borders, Paragraph, Widget, Wrap},
     Terminal,
 };
 
+#[cfg(feature = "crossterm")]
+use crate::utils::Wait;
 use crate::{
-    menu::{FromMutable, Mutable, UsesMutable},
-    utils::Depth,
+    field::{Navigation, Theme},
+    menu::{DynMenu, FromMutable, MenuState, Mutable, UsesMutable, Visit},
+    utils::{logged, navigate, Depth},
     MenuError, MenuResult,
 };
+#[cfg(feature = "crossterm")]
+use std::time::Duration;
 
-use self::event::{Event, KeyEvent};
+use self::event::{cell_width, Action, Event, KeyEvent, KeyMap};
+pub use self::files::TuiFilePicker;
+use self::prompt::PromptWidget;
+pub use self::prompt::TuiPrompt;
 
 #[cfg(feature = "crossterm")]
 #[cfg_attr(nightly, doc(cfg(feature = "crossterm")))]
@@ -50,8 +66,73 @@ use self::termion::{
 /// and the `Color` field corresponds to the background color of the menu field.
 pub type FieldStyle = (Style, Color);
 
+/// Defines how the fields of a [`TuiMenu`] are positioned.
+///
+/// The up and down keys move the selection to the field above or below,
+/// and the left and right keys to the previous or the next field (see [`KeyMap`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MenuLayout {
+    /// The fields are listed in a single column (default layout).
+    #[default]
+    Vertical,
+    /// The fields are displayed on a single row, like a menu bar.
+    Horizontal,
+    /// The fields are displayed in a grid, filled row by row.
+    Grid {
+        /// The amount of columns of the grid.
+        columns: usize,
+    },
+}
+
+impl MenuLayout {
+    /// Returns the amount of columns used to display the given amount of fields.
+    pub(crate) fn columns(self, len: usize) -> usize {
+        match self {
+            Self::Vertical => 1,
+            Self::Horizontal => len.max(1),
+            Self::Grid { columns } => columns.max(1),
+        }
+    }
+}
+
 type Reader = fn() -> io::Result<Event>;
 
+/// The writer receiving the announcements of the selected fields.
+struct Announcer<'a>(Box<dyn Write + 'a>);
+
+impl fmt::Debug for Announcer<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Announcer")
+    }
+}
+
+/// A navigation command given by the application, applied before reading the events
+/// from the terminal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Command {
+    /// Moves the selection to the field at the given index.
+    Select(usize),
+    /// Selects the current field.
+    Enter,
+    /// Goes back to the previous menu page.
+    Back,
+    /// Goes to the submenu of the given state, from the top-level menu.
+    Goto(MenuState),
+}
+
+/// Returns the default styles of the selected field and of the other fields.
+pub(crate) fn default_styles() -> (FieldStyle, FieldStyle) {
+    (
+        (
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::White),
+            Color::Black,
+        ),
+        (Style::default().fg(Color::Black), Color::White),
+    )
+}
+
 /// Defines a tui menu, with a title, and the fields.
 ///
 /// It handles the [terminal](Terminal) and the [style](Style) of the fields.
@@ -63,6 +144,16 @@ pub struct TuiMenu<'a, B: Backend> {
     fields: TuiFields<'a, B>,
     term: Mutable<'a, Terminal<B>>,
     once: bool,
+    wrap: bool,
+    show_help: bool,
+    layout: MenuLayout,
+    keys: KeyMap,
+    descriptions: HashMap<String, &'a str>,
+    announcer: Option<Announcer<'a>>,
+    state: MenuState,
+    commands: VecDeque<Command>,
+    #[cfg(feature = "crossterm")]
+    timeout: Option<Duration>,
 }
 
 impl<'a, B: Backend> UsesMutable<Terminal<B>> for TuiMenu<'a, B> {
@@ -81,20 +172,26 @@ impl<'a, B: Backend> UsesMutable<Terminal<B>> for TuiMenu<'a, B> {
 
 impl<'a, B: Backend> FromMutable<'a, Terminal<B>, TuiFields<'a, B>> for TuiMenu<'a, B> {
     fn new(term: Mutable<'a, Terminal<B>>, fields: TuiFields<'a, B>) -> Self {
+        let (s_style, f_style) = default_styles();
         Self {
             block: Block::default()
                 .borders(Borders::all())
                 .title_alignment(Alignment::Center),
-            s_style: (
-                Style::default()
-                    .add_modifier(Modifier::BOLD)
-                    .fg(Color::White),
-                Color::Black,
-            ),
-            f_style: (Style::default().fg(Color::Black), Color::White),
+            s_style,
+            f_style,
             fields,
             term,
             once: false,
+            wrap: true,
+            show_help: false,
+            layout: MenuLayout::Vertical,
+            keys: KeyMap::default(),
+            descriptions: HashMap::new(),
+            announcer: None,
+            state: MenuState::new(),
+            commands: VecDeque::new(),
+            #[cfg(feature = "crossterm")]
+            timeout: None,
         }
     }
 }
@@ -170,6 +267,13 @@ impl<'a, B: Backend> TuiMenu<'a, B> {
         self
     }
 
+    /// Defines the styles of the selected field and of the other fields
+    /// from the given [theme](Theme).
+    pub fn theme<T: Theme>(mut self, theme: T) -> Self {
+        (self.s_style, self.f_style) = theme.tui_styles();
+        self
+    }
+
     /// Defines the block drawn by the menu.
     ///
     /// This function can be used to set a title to the menu.
@@ -185,9 +289,142 @@ impl<'a, B: Backend> TuiMenu<'a, B> {
         self
     }
 
+    /// Defines if the selection wraps around when moving past the first or the last field
+    /// (`true` by default).
+    ///
+    /// If it doesn't, the selection stays on the first or the last field.
+    pub fn selection_wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Defines how the fields are positioned (see [`MenuLayout`]).
+    ///
+    /// A horizontal layout is useful for menu bars, and a grid for palettes of many
+    /// short fields.
+    pub fn layout(mut self, layout: MenuLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Defines the keys navigating through the menu pages (see [`KeyMap`]).
+    pub fn key_map(mut self, keys: KeyMap) -> Self {
+        self.keys = keys;
+        self
+    }
+
+    /// Defines if a help line listing the keys, like `↑/↓ move · Enter select · Esc back · q quit`,
+    /// is displayed at the bottom of the menu (`false` by default).
+    ///
+    /// The help line displays the first key of each action of the [key map](TuiMenu::key_map).
+    pub fn show_help(mut self, show: bool) -> Self {
+        self.show_help = show;
+        self
+    }
+
+    /// Assigns a long description to a menu, displayed as a wrapped paragraph
+    /// below its fields.
+    ///
+    /// The menu is identified by the messages of its parent fields, separated by `/`.
+    /// The top-level menu is identified by an empty string.
+    pub fn description<K: Into<String>>(mut self, key: K, desc: &'a str) -> Self {
+        self.descriptions.insert(key.into(), desc);
+        self
+    }
+
+    /// Defines the writer receiving a plain sentence like `Selected 2 of 5: Settings`
+    /// on its own line each time the user moves the selection with the keyboard.
+    ///
+    /// The terminal is drawn by the menu, so the writer is usually an auxiliary output,
+    /// like a file or a pipe read by a screen reader.
+    pub fn announce_to<W: Write + 'a>(mut self, writer: W) -> Self {
+        self.announcer = Some(Announcer(Box::new(writer)));
+        self
+    }
+
+    /// Defines the submenu displayed first when running the menu, e.g. the submenu
+    /// saved by the previous run of the program.
+    ///
+    /// See [`RawMenu::restore_state`](crate::menu::RawMenu::restore_state)
+    /// for more information.
+    pub fn restore_state(mut self, state: MenuState) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Returns the submenu displayed last by the previous run of the menu,
+    /// or the state given to [`TuiMenu::restore_state`] if it has not run yet.
+    pub fn save_state(&self) -> MenuState {
+        self.state.clone()
+    }
+
+    /// Moves the selection to the field at the given index of the current menu.
+    ///
+    /// Like the other navigation commands ([`TuiMenu::enter`], [`TuiMenu::back`]
+    /// and [`TuiMenu::goto_path`]), it is applied on the next run of the menu,
+    /// before reading the events from the terminal, in the order the commands are given.
+    /// This lets the application drive the menu from code, e.g. to open a deep link
+    /// or in tests. If the index is out of range, the command is ignored.
+    ///
+    /// The commands not applied by a run, e.g. because the user quit the menu
+    /// in a mapped function, are discarded.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::{prelude::*, tui::*, tui_fields};
+    /// # fn main() -> MenuResult {
+    /// let mut menu = TuiMenu::try_from(tui_fields![
+    ///     ("Play", TuiKind::Quit),
+    ///     (
+    ///         "Settings",
+    ///         TuiKind::Parent(tui_fields![("Sound", TuiKind::Back(0)), ("Back", TuiKind::Back(0))]),
+    ///     ),
+    /// ])?;
+    ///
+    /// // Displays the "Back" field of the settings, as if the user selected it.
+    /// menu.select(1).enter().select(1);
+    /// menu.run()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn select(&mut self, index: usize) -> &mut Self {
+        self.commands.push_back(Command::Select(index));
+        self
+    }
+
+    /// Selects the current field, e.g. to enter its submenu.
+    ///
+    /// See [`TuiMenu::select`] for more information.
+    pub fn enter(&mut self) -> &mut Self {
+        self.commands.push_back(Command::Enter);
+        self
+    }
+
+    /// Goes back to the previous menu page, closing the menu if it is the top-level menu.
+    ///
+    /// See [`TuiMenu::select`] for more information.
+    pub fn back(&mut self) -> &mut Self {
+        self.commands.push_back(Command::Back);
+        self
+    }
+
+    /// Goes to the submenu identified by the given indexes of its parent fields,
+    /// from the top-level menu.
+    ///
+    /// The path is followed like a [restored state](TuiMenu::restore_state), stopping
+    /// at the first index that does not correspond to a parent field.
+    /// See [`TuiMenu::select`] for more information.
+    pub fn goto_path(&mut self, path: &[usize]) -> &mut Self {
+        self.commands
+            .push_back(Command::Goto(MenuState::from(path.to_vec())));
+        self
+    }
+
     /// Runs the menu with the given area and the function to read the events from.
     fn run_with_read(&mut self, read_fn: Reader, area: Rect) -> MenuResult {
-        run_with(
+        let help = self.show_help.then(|| self.keys.hints(self.layout));
+        let mut visit = Visit::new(&self.state);
+        let out = run_with(
             &mut RunParams {
                 term: self.term.deref_mut(),
                 area,
@@ -195,17 +432,39 @@ impl<'a, B: Backend> TuiMenu<'a, B> {
                 f_style: &self.f_style,
                 read_fn,
                 once: self.once,
+                wrap: self.wrap,
+                layout: self.layout,
+                keys: &self.keys,
+                help: help.as_deref(),
+                descriptions: &self.descriptions,
+                path: Vec::new(),
+                visit: &mut visit,
+                commands: &mut self.commands,
+                announcer: self.announcer.as_mut().map(|a| &mut *a.0 as &mut dyn Write),
+                #[cfg(feature = "crossterm")]
+                wait: Wait::timeout(self.timeout),
             },
             &self.block,
             self.fields,
-        )
-        .map(|_| ())
+        );
+        self.state = visit.state();
+        self.commands.clear();
+        out.map(|_| ())
     }
 }
 
 #[cfg(feature = "crossterm")]
 #[cfg_attr(nightly, doc(cfg(feature = "crossterm")))]
 impl<'a> TuiMenu<'a, Crossterm> {
+    /// Defines the time the user has to press a key, each time the menu is run.
+    ///
+    /// If the user does not press any key in time, running the menu returns
+    /// a [`MenuError::Timeout`] error. The timeout stops once the user pressed a key.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Runs the menu using the crossterm backend, using the terminal size.
     pub fn run(&mut self) -> MenuResult {
         self.run_with(self.term.size()?)
@@ -224,6 +483,14 @@ impl<'a> TuiMenu<'a, Crossterm> {
     }
 }
 
+#[cfg(feature = "crossterm")]
+#[cfg_attr(nightly, doc(cfg(feature = "crossterm")))]
+impl DynMenu for TuiMenu<'_, Crossterm> {
+    fn run(&mut self) -> MenuResult {
+        TuiMenu::run(self)
+    }
+}
+
 #[cfg(feature = "termion")]
 #[cfg_attr(nightly, doc(cfg(feature = "termion")))]
 impl<'a> TuiMenu<'a, Termion> {
@@ -245,28 +512,80 @@ impl<'a> TuiMenu<'a, Termion> {
     }
 }
 
+#[cfg(feature = "termion")]
+#[cfg_attr(nightly, doc(cfg(feature = "termion")))]
+impl DynMenu for TuiMenu<'_, Termion> {
+    fn run(&mut self) -> MenuResult {
+        TuiMenu::run(self)
+    }
+}
+
+/// A field displayed by the menu widget, with its optional icon and style.
+struct WidgetField<'a> {
+    msg: &'a str,
+    icon: Option<&'a str>,
+    style: Option<Style>,
+}
+
 /// Contains the information displayed to the terminal at a specific moment.
 struct MenuWidget<'a> {
-    fields: Vec<&'a str>,
+    fields: Vec<WidgetField<'a>>,
     block: Block<'a>,
     s_style: &'a FieldStyle,
     f_style: &'a FieldStyle,
     selected: usize,
+    // The amount of columns the fields are displayed in.
+    columns: usize,
+    description: Option<&'a str>,
+    help: Option<&'a str>,
 }
 
 impl<'a> Widget for MenuWidget<'a> {
     fn render(self, area @ Rect { x, y, width, .. }: Rect, buf: &mut Buffer) {
         self.block.render(area, buf);
+        let rows = self.fields.len().div_ceil(self.columns) as u16;
+        let cell = cell_width(width, self.columns);
 
-        for (i, msg) in self.fields.into_iter().enumerate() {
-            let (fg_style, bg_style) = if i == self.selected {
+        for (i, field) in self.fields.into_iter().enumerate() {
+            let (mut fg_style, bg_style) = if i == self.selected {
                 (self.s_style.0, Style::default().bg(self.s_style.1))
             } else {
                 (self.f_style.0, Style::default().bg(self.f_style.1))
             };
+            if let Some(style) = field.style {
+                fg_style = fg_style.patch(style);
+            }
+
+            let text = match field.icon {
+                Some(icon) => format!("{} {}", icon, field.msg),
+                None => field.msg.to_owned(),
+            };
+            // The fields are displayed row by row, each in a cell of the same width.
+            let fx = x + 1 + (i % self.columns) as u16 * cell;
+            let fy = y + 1 + (i / self.columns) as u16;
+            let text_width = cell.saturating_sub(2) as usize;
+            buf.set_stringn(fx + 1, fy, text, text_width, fg_style);
+            buf.set_style(Rect::new(fx, fy, cell, 1), bg_style);
+        }
 
-            buf.set_stringn(x + 2, y + 1 + i as u16, msg, width as usize - 4, fg_style);
-            buf.set_style(Rect::new(x + 1, y + 1 + i as u16, width - 2, 1), bg_style);
+        // The help line is on the last row inside the block.
+        let mut bottom = area.bottom().saturating_sub(1);
+        if let Some(help) = self.help {
+            if bottom > y + 1 + rows {
+                bottom -= 1;
+                buf.set_stringn(x + 2, bottom, help, width as usize - 4, Style::default());
+            }
+        }
+
+        // The description is below the fields, so their positions stay the same for the mouse.
+        if let Some(desc) = self.description {
+            let top = y + 2 + rows;
+            if top < bottom {
+                Paragraph::new(desc).wrap(Wrap { trim: true }).render(
+                    Rect::new(x + 2, top, width.saturating_sub(4), bottom - top),
+                    buf,
+                );
+            }
         }
     }
 }
@@ -280,17 +599,57 @@ struct RunParams<'a, B: Backend> {
     f_style: &'a FieldStyle,
     read_fn: Reader,
     once: bool,
+    wrap: bool,
+    layout: MenuLayout,
+    keys: &'a KeyMap,
+    // The hints of the keys, if the help line is displayed.
+    help: Option<&'a str>,
+    descriptions: &'a HashMap<String, &'a str>,
+    // The messages of the parent menus of the current menu.
+    path: Vec<String>,
+    visit: &'a mut Visit,
+    // The navigation commands given by the application, still to apply.
+    commands: &'a mut VecDeque<Command>,
+    announcer: Option<&'a mut dyn Write>,
+    #[cfg(feature = "crossterm")]
+    wait: Wait,
+}
+
+impl<B: Backend> RunParams<'_, B> {
+    /// Reads the next event, or returns a [`MenuError::Timeout`] error if the user
+    /// did not press any key before the deadline.
+    fn read(&mut self) -> MenuResult<Event> {
+        #[cfg(feature = "crossterm")]
+        if !self.wait.poll()? {
+            return Err(MenuError::Timeout);
+        }
+        let event = (self.read_fn)()?;
+        // The timeout stops once the user pressed a key.
+        #[cfg(feature = "crossterm")]
+        if !matches!(event, Event::Resize(..)) {
+            self.wait.deadline = None;
+        }
+        Ok(event)
+    }
 }
 
 /// Prints out the menu to the terminal.
-fn show_menu<B: Backend>(
+fn show_menu<B: Backend, S: AsRef<str>>(
     params: &mut RunParams<B>,
     block: &Block,
-    fields: TuiFields<B>,
+    fields: &[TuiField<B, S>],
     selected: usize,
+    description: Option<&str>,
 ) -> io::Result<()> {
-    // The messages displayed
-    let msg_list: Vec<&str> = fields.iter().map(|field| field.0).collect();
+    // The messages displayed, with their icon and style
+    let msg_list: Vec<WidgetField> = fields
+        .iter()
+        .map(|field| WidgetField {
+            msg: field.msg.as_ref(),
+            icon: field.icon,
+            style: field.style,
+        })
+        .collect();
 
     params
         .term
@@ -302,6 +661,9 @@ fn show_menu<B: Backend>(
                     s_style: params.s_style,
                     f_style: params.f_style,
                     selected,
+                    columns: params.layout.columns(fields.len()),
+                    description,
+                    help: params.help,
                 },
                 params.area,
             );
@@ -309,17 +671,29 @@ fn show_menu<B: Backend>(
         .map(|_| ())
 }
 
-/// Handles the field selected by the user.
+/// Handles the field selected by the user, at the given index among the fields
+/// of the current menu.
 fn handle_field<B: Backend>(
     params: &mut RunParams<B>,
     block: &Block,
-    (msg, kind): &TuiField<B>,
+    index: usize,
+    msg: &str,
+    kind: &TuiKind<B>,
 ) -> MenuResult<Depth> {
     use Depth::*;
 
+    /// Returns the depth of the parent menu from the depth returned by its sub-menu.
+    fn parent_depth(depth: Depth) -> Depth {
+        match depth {
+            Current | Back(0) => Current,
+            Back(i) => Back(i - 1),
+            depth => depth,
+        }
+    }
+
     Ok(match kind {
         TuiKind::Map(b) => {
-            b(params.term)?;
+            logged(b(params.term), msg)?;
             if params.once {
                 Quit
             } else {
@@ -327,49 +701,313 @@ fn handle_field<B: Backend>(
                 Current
             }
         }
-        TuiKind::Parent(fields) => match run_with(params, &block.clone().title(*msg), fields)? {
-            Current | Back(0) => Current,
-            Quit => Quit,
-            Back(i) => Back(i - 1),
-        },
+        TuiKind::MapNavigate(b) => {
+            let nav = logged(b(params.term), msg)?;
+            match navigate(nav, &params.path, params.once)? {
+                Current => {
+                    params.term.clear()?;
+                    Current
+                }
+                depth => depth,
+            }
+        }
+        TuiKind::Prompt(prompt) => run_prompt(params, &block.clone().title(msg), prompt)?,
+        TuiKind::Files(files) => run_files(params, block, files)?,
+        TuiKind::Parent(fields) => {
+            params.path.push(msg.to_owned());
+            params.visit.enter(index);
+            let depth = run_with(params, &block.clone().title(msg), fields);
+            params.visit.leave();
+            params.path.pop();
+            parent_depth(depth?)
+        }
+        TuiKind::Dynamic(f) => {
+            params.path.push(msg.to_owned());
+            params.visit.enter(index);
+            let depth = run_dynamic_with(params, &block.clone().title(msg), f);
+            params.visit.leave();
+            params.path.pop();
+            parent_depth(depth?)
+        }
         TuiKind::Back(0) => Current,
         TuiKind::Back(i) => Back(i - 1),
         TuiKind::Quit => Quit,
     })
 }
 
-/// Handles the key pressed by the user.
-fn handle_key<B: Backend>(
+/// Prompts the written field in a popup, until the user submits a correct input
+/// or closes the popup.
+fn run_prompt<B: Backend>(
     params: &mut RunParams<B>,
     block: &Block,
-    fields: TuiFields<B>,
+    prompt: &TuiPrompt<B>,
+) -> MenuResult<Depth> {
+    let title = prompt.title();
+    let mut input = String::new();
+
+    loop {
+        params.term.draw(|f| {
+            f.render_widget(
+                PromptWidget {
+                    block: block.clone(),
+                    title: &title,
+                    input: &input,
+                    style: params.s_style.0.bg(params.s_style.1),
+                },
+                params.area,
+            )
+        })?;
+
+        if let Event::Key(k) = params.read()? {
+            match Action::from_popup_key(k, params.keys) {
+                Some(Action::Quit) => return Ok(Depth::Quit),
+                Some(_) => return Ok(Depth::Current),
+                None => (),
+            }
+            match k {
+                KeyEvent::Enter => match prompt.submit(params.term, &input) {
+                    Some(res) => {
+                        logged(res, &title)?;
+                        if params.once {
+                            return Ok(Depth::Quit);
+                        }
+                        params.term.clear()?;
+                        return Ok(Depth::Current);
+                    }
+                    None => input.clear(),
+                },
+                KeyEvent::Backspace => {
+                    input.pop();
+                }
+                KeyEvent::Char(c) => input.push(c),
+                _ => (),
+            }
+        }
+    }
+}
+
+/// Displays the entries of the directories of the file picker, until the user selects a file
+/// or closes the directory listed first.
+///
+/// Each directory is a sub-menu level, titled by its path, so going back displays
+/// the previous directory.
+fn run_files<B: Backend>(
+    params: &mut RunParams<B>,
+    block: &Block,
+    files: &TuiFilePicker<B>,
+) -> MenuResult<Depth> {
+    let mut dir = files.picker.start_dir()?;
+    let mut levels = Vec::new();
+    let mut selected = 0;
+
+    loop {
+        let entries = files.picker.entries(&dir)?;
+        if entries.is_empty() {
+            return Ok(Depth::Current);
+        }
+        selected = selected.min(entries.len() - 1);
+
+        let block = block.clone().title(dir.to_string_lossy().into_owned());
+        // The entries are handled below, so the kind of the fields is never used.
+        let fields: Vec<_> = entries
+            .iter()
+            .map(|entry| TuiField::new(entry.label.as_str(), TuiKind::Quit))
+            .collect();
+        show_menu(params, &block, &fields, selected, None)?;
+
+        let action = match params.read()? {
+            Event::Key(k) => Action::from_key(k, params.keys),
+            Event::Mouse(m) => {
+                let columns = params.layout.columns(fields.len());
+                Action::from_mouse(m, params.area, fields.len(), columns)
+            }
+            Event::Resize(..) => None,
+        };
+        let i = match action {
+            Some(Action::Select) => selected,
+            Some(Action::SelectAt(i)) => i,
+            action => match handle_action(params, &block, &fields, &mut selected, action)? {
+                Depth::Current => continue,
+                Depth::Back(0) => match levels.pop() {
+                    Some(prev) => {
+                        dir = prev;
+                        selected = 0;
+                        continue;
+                    }
+                    None => return Ok(Depth::Current),
+                },
+                depth => return Ok(depth),
+            },
+        };
+
+        let entry = &entries[i];
+        if entry.is_dir {
+            levels.push(mem::replace(&mut dir, entry.path.clone()));
+            selected = 0;
+        } else {
+            logged(files.submit(params.term, entry.path.clone()), &entry.label)?;
+            if params.once {
+                return Ok(Depth::Quit);
+            }
+            params.term.clear()?;
+            return Ok(Depth::Current);
+        }
+    }
+}
+
+/// Handles the action performed by the user.
+fn handle_action<B: Backend, S: AsRef<str>>(
+    params: &mut RunParams<B>,
+    block: &Block,
+    fields: &[TuiField<B, S>],
     selected: &mut usize,
-    k: KeyEvent,
+    action: Option<Action>,
 ) -> MenuResult<Depth> {
     use Depth::*;
 
-    Ok(match k {
-        KeyEvent::Char('q') | KeyEvent::Ctrl('c') => Quit,
-        KeyEvent::Esc => Back(0),
-        KeyEvent::Up | KeyEvent::Left if *selected == 0 => {
-            *selected = fields.len() - 1;
+    let moved = matches!(
+        action,
+        Some(
+            Action::Up | Action::Down | Action::Prev | Action::Next | Action::First | Action::Last
+        )
+    );
+    let (len, columns) = (fields.len(), params.layout.columns(fields.len()));
+    let depth = match action {
+        Some(Action::Quit) => Quit,
+        Some(Action::Back) => Back(0),
+        Some(Action::Up) if *selected < columns => {
+            // Wraps to the last field of the same column.
+            if params.wrap {
+                *selected += columns * ((len - 1 - *selected) / columns);
+            }
+            Current
+        }
+        Some(Action::Up) => {
+            *selected -= columns;
             Current
         }
-        KeyEvent::Up | KeyEvent::Left => {
+        Some(Action::Down) if *selected + columns >= len => {
+            // Wraps to the first field of the same column.
+            if params.wrap {
+                *selected %= columns;
+            }
+            Current
+        }
+        Some(Action::Down) => {
+            *selected += columns;
+            Current
+        }
+        Some(Action::Prev) if *selected == 0 => {
+            if params.wrap {
+                *selected = fields.len() - 1;
+            }
+            Current
+        }
+        Some(Action::Prev) => {
             *selected -= 1;
             Current
         }
-        KeyEvent::Down | KeyEvent::Right if *selected == fields.len() - 1 => {
-            *selected = 0;
+        Some(Action::Next) if *selected == fields.len() - 1 => {
+            if params.wrap {
+                *selected = 0;
+            }
             Current
         }
-        KeyEvent::Down | KeyEvent::Right => {
+        Some(Action::Next) => {
             *selected += 1;
             Current
         }
-        KeyEvent::Enter | KeyEvent::Char(' ') => handle_field(params, block, &fields[*selected])?,
-        _ => Current,
-    })
+        Some(Action::First) => {
+            *selected = 0;
+            Current
+        }
+        Some(Action::Last) => {
+            *selected = fields.len() - 1;
+            Current
+        }
+        Some(Action::Select) => {
+            let field = &fields[*selected];
+            log_event!(info, "selected the field `{}`", field.msg.as_ref());
+            handle_field(params, block, *selected, field.msg.as_ref(), &field.kind)?
+        }
+        Some(Action::SelectAt(i)) => {
+            *selected = i;
+            let field = &fields[i];
+            log_event!(info, "selected the field `{}`", field.msg.as_ref());
+            handle_field(params, block, i, field.msg.as_ref(), &field.kind)?
+        }
+        None => Current,
+    };
+
+    if let (true, Some(announcer)) = (moved, params.announcer.as_mut()) {
+        let msg = fields[*selected].msg.as_ref();
+        writeln!(
+            announcer,
+            "Selected {} of {}: {}",
+            *selected + 1,
+            fields.len(),
+            msg
+        )?;
+        announcer.flush()?;
+    }
+    Ok(depth)
+}
+
+/// Displays the fields and handles the next event read from the terminal.
+///
+/// It returns the depth level resulting from the event.
+fn handle_event<B: Backend, S: AsRef<str>>(
+    params: &mut RunParams<B>,
+    block: &Block,
+    fields: &[TuiField<B, S>],
+    selected: &mut usize,
+) -> MenuResult<Depth> {
+    // The parent fields of the restored state, then the commands of the application,
+    // are handled without waiting for an event.
+    if let Some(i) = params.visit.next_restored() {
+        match fields.get(i) {
+            Some(field) if field.kind.is_parent() => {
+                *selected = i;
+                return handle_field(params, block, i, field.msg.as_ref(), &field.kind);
+            }
+            _ => params.visit.cancel_restore(),
+        }
+    }
+
+    if let Some(command) = params.commands.pop_front() {
+        return match command {
+            Command::Select(i) => {
+                if i < fields.len() {
+                    *selected = i;
+                }
+                Ok(Depth::Current)
+            }
+            Command::Enter => handle_action(params, block, fields, selected, Some(Action::Select)),
+            Command::Back => handle_action(params, block, fields, selected, Some(Action::Back)),
+            Command::Goto(state) => {
+                // Goes back to the top-level menu, which then enters the submenus of the state.
+                params.visit.goto(&state);
+                Ok(match params.visit.depth() {
+                    0 => Depth::Current,
+                    depth => Depth::Back(depth - 1),
+                })
+            }
+        };
+    }
+    params.visit.shown();
+
+    let description = params.descriptions.get(&params.path.join("/")).copied();
+    show_menu(params, block, fields, *selected, description)?;
+
+    let action = match params.read()? {
+        Event::Key(k) => Action::from_key(k, params.keys),
+        Event::Mouse(m) => {
+            let columns = params.layout.columns(fields.len());
+            Action::from_mouse(m, params.area, fields.len(), columns)
+        }
+        Event::Resize(..) => None,
+    };
+    handle_action(params, block, fields, selected, action)
 }
 
 /// Recursive function used to run the current state menu.
@@ -383,25 +1021,154 @@ fn run_with<B: Backend>(
     let mut selected = 0;
 
     loop {
-        show_menu(params, block, fields, selected)?;
+        match handle_event(params, block, fields, &mut selected)? {
+            Depth::Current => (),
+            depth => return Ok(depth),
+        }
+    }
+}
 
-        if let Event::Key(k) = (params.read_fn)()? {
-            match handle_key(params, block, fields, &mut selected, k)? {
-                Depth::Quit => return Ok(Depth::Quit),
-                Depth::Back(i) => return Ok(Depth::Back(i)),
-                Depth::Current => (),
-            }
+/// Runs the current state of a dynamic menu.
+///
+/// It behaves like the [`run_with`] function, but the fields are generated
+/// by the given function before each display of the menu.
+fn run_dynamic_with<B: Backend>(
+    params: &mut RunParams<B>,
+    block: &Block,
+    f: &TuiDynFields<B>,
+) -> MenuResult<Depth> {
+    let mut selected = 0;
+
+    loop {
+        let fields = f(params.term);
+        if fields.is_empty() {
+            return Ok(Depth::Back(0));
+        }
+        // The amount of fields may have changed since the last display.
+        selected = selected.min(fields.len() - 1);
+
+        match handle_event(params, block, &fields, &mut selected)? {
+            Depth::Current => (),
+            depth => return Ok(depth),
         }
     }
 }
 
 /// A tui menu field.
 ///
-/// The string slice corersponds to the message displayed in the list,
-/// and the kind corresponds to its behavior.
+/// It is made of the message displayed in the list, and the kind corresponding to its behavior
+/// (see [`TuiKind`] for more information). It may also override the style of the field,
+/// and display an icon before its message, e.g. to highlight the destructive actions
+/// or to mark the parent fields.
 ///
-/// See [`TuiKind`] for more information.
-pub type TuiField<'a, B> = (&'a str, TuiKind<'a, B>);
+/// The message is a string slice by default, and an owned string for the
+/// [fields generated at runtime](TuiDynField).
+///
+/// The fields are usually written as `(message, kind)` tuples inside the
+/// [`tui_fields!`](crate::tui_fields) macro, or converted from the tuples
+/// with the [`From`] implementation.
+///
+/// # Example
+///
+/// ```
+/// # use ezmenulib::{tui::{crossterm::Crossterm, *}, tui_fields};
+/// # use tui::style::{Color, Style};
+/// let reset = [
+///     TuiField::new("Reset everything", TuiKind::Quit).style(Style::default().fg(Color::Red)),
+///     TuiField::from(("Cancel", TuiKind::Back(0))),
+/// ];
+/// let fields: TuiFields<Crossterm> = tui_fields![
+///     ("Play", TuiKind::Quit),
+///     ("Reset", TuiKind::Parent(&reset)),
+/// ];
+/// assert_eq!(fields[1].msg, "Reset");
+/// ```
+pub struct TuiField<'a, B: Backend, M = &'a str> {
+    /// The message displayed in the list.
+    pub msg: M,
+    /// The behavior of the field.
+    pub kind: TuiKind<'a, B>,
+    /// The style applied over the style of the selected field or of the other fields.
+    pub style: Option<Style>,
+    /// The icon displayed before the message, e.g. an emoji or a `▶` marker.
+    pub icon: Option<&'a str>,
+}
+
+impl<'a, B: Backend, M> TuiField<'a, B, M> {
+    /// Returns the field with the given message and kind, without any style or icon.
+    pub const fn new(msg: M, kind: TuiKind<'a, B>) -> Self {
+        Self {
+            msg,
+            kind,
+            style: None,
+            icon: None,
+        }
+    }
+
+    /// Defines the style of the field, applied over the style of the selected field
+    /// or of the other fields.
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Defines the icon displayed before the message of the field.
+    pub const fn icon(mut self, icon: &'a str) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+}
+
+impl<'a, B: Backend, M> From<(M, TuiKind<'a, B>)> for TuiField<'a, B, M> {
+    fn from((msg, kind): (M, TuiKind<'a, B>)) -> Self {
+        Self::new(msg, kind)
+    }
+}
+
+impl<B: Backend, M: fmt::Debug> fmt::Debug for TuiField<'_, B, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TuiField")
+            .field("msg", &self.msg)
+            .field("kind", &self.kind)
+            .field("style", &self.style)
+            .field("icon", &self.icon)
+            .finish()
+    }
+}
+
+/// Builds a slice of [tui fields](crate::tui::TuiField) from `(message, kind)` tuples.
+///
+/// Like a slice of tuples, the slice is borrowed for the `'static` lifetime if the kinds are
+/// constant, so it can be nested in the [`TuiKind::Parent`](crate::tui::TuiKind::Parent) variant.
+///
+/// # Example
+///
+/// ```no_run
+/// # use ezmenulib::{prelude::*, tui::{crossterm::Crossterm, *}, tui_fields};
+/// # fn main() -> MenuResult {
+/// let mut menu = TuiMenu::<Crossterm>::try_from(tui_fields![
+///     ("Play", TuiKind::Quit),
+///     (
+///         "Settings",
+///         TuiKind::Parent(tui_fields![("Sound", TuiKind::Back(0)), ("Back", TuiKind::Back(0))]),
+///     ),
+/// ])?;
+/// menu.run()?;
+/// # Ok(()) }
+/// ```
+#[macro_export]
+macro_rules! tui_fields {
+    ($(($msg:expr, $kind:expr $(,)?)),* $(,)?) => {
+        &[$(
+            $crate::tui::TuiField {
+                msg: $msg,
+                kind: $kind,
+                style: ::std::option::Option::None,
+                icon: ::std::option::Option::None,
+            }
+        ),*]
+    };
+}
 
 /// The tui menu fields.
 ///
@@ -415,14 +1182,54 @@ pub type TuiFields<'a, B> = &'a [TuiField<'a, B>];
 /// This function is called right after the user selected the corresponding field.
 pub type TuiBinding<B> = dyn Fn(&mut Terminal<B>) -> MenuResult;
 
+/// Corresponds to the function mapped to a field, returning the navigation instruction
+/// of the menu.
+///
+/// See [`TuiKind::MapNavigate`] for more information.
+pub type TuiNavBinding<B> = dyn Fn(&mut Terminal<B>) -> MenuResult<Navigation>;
+
+/// A tui menu field generated at runtime.
+///
+/// Unlike the default [`TuiField`], it owns its message, so it can be built from runtime data.
+pub type TuiDynField<'a, B> = TuiField<'a, B, String>;
+
+/// Corresponds to the function generating the fields of a dynamic tui menu.
+///
+/// This function is called each time the menu is displayed, so the fields
+/// are always up to date.
+///
+/// See [`TuiKind::Dynamic`] for more information.
+pub type TuiDynFields<'a, B> = dyn Fn(&mut Terminal<B>) -> Vec<TuiDynField<'a, B>> + 'a;
+
 /// Defines the behavior of a [tui field](TuiField).
 pub enum TuiKind<'a, B: Backend> {
     /// Maps a function to call right after the user selects the field.
     Map(&'a TuiBinding<B>),
+    /// Maps a function returning the navigation instruction of the menu to call right after
+    /// the user selects the field.
+    ///
+    /// See [`Kind::MapNavigate`](crate::field::Kind::MapNavigate) for more information.
+    MapNavigate(&'a TuiNavBinding<B>),
+    /// Prompts a written field in a popup right after the user selects the field,
+    /// then calls the function of the prompt with the parsed value.
+    ///
+    /// See [`TuiPrompt`] for more information.
+    Prompt(&'a TuiPrompt<'a, B>),
+    /// Displays the entries of the directories of a file picker right after the user selects
+    /// the field, then calls the function of the file picker with the path of the selected file.
+    ///
+    /// See [`TuiFilePicker`] for more information.
+    Files(&'a TuiFilePicker<'a, B>),
     /// Defines the current field as a parent menu of a sub-menu defined by its given fields.
     Parent(TuiFields<'a, B>),
+    /// Defines the current field as a parent menu of a sub-menu whose fields are
+    /// generated by the given function.
+    ///
+    /// The fields are generated again each time the sub-menu is displayed.
+    /// If the function returns no field, the user goes back to the current page.
+    Dynamic(&'a TuiDynFields<'a, B>),
     /// Allows the user to go back to the given depth level from the current running page.
-    /// 
+    ///
     /// The depth level of the current running page is at `0`, meaning it will stay at
     /// the current level if the index is at `0` when the user will select the field.
     Back(usize),
@@ -430,12 +1237,23 @@ pub enum TuiKind<'a, B: Backend> {
     Quit,
 }
 
+impl<B: Backend> TuiKind<'_, B> {
+    /// Returns `true` if the field displays a sub-menu when it is selected.
+    fn is_parent(&self) -> bool {
+        matches!(self, Self::Parent(_) | Self::Dynamic(_))
+    }
+}
+
 impl<'a, B: Backend> fmt::Debug for TuiKind<'a, B> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("Field::")?;
         match self {
             Self::Map(_) => f.debug_tuple("Map").finish(),
+            Self::MapNavigate(_) => f.debug_tuple("MapNavigate").finish(),
+            Self::Prompt(p) => f.debug_tuple("Prompt").field(p).finish(),
+            Self::Files(p) => f.debug_tuple("Files").field(p).finish(),
             Self::Parent(fields) => f.debug_tuple("Parent").field(fields).finish(),
+            Self::Dynamic(_) => f.debug_tuple("Dynamic").finish(),
             Self::Back(i) => f.debug_tuple("Back").field(i).finish(),
             Self::Quit => write!(f, "Quit"),
         }
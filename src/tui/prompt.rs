@@ -0,0 +1,118 @@
+//! Module defining the written fields prompted inside the tui menus.
+
+use std::{fmt, str::FromStr};
+
+use tui::{
+    backend::Backend,
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+    Terminal,
+};
+
+use crate::{
+    field::{Format, Written},
+    MenuResult,
+};
+
+/// The function called with the input of the user, returning `None` if the input is incorrect.
+type Handler<'a, B> = Box<dyn Fn(&mut Terminal<B>, &str) -> Option<MenuResult> + 'a>;
+
+/// A written field prompted inside a tui menu, in a popup.
+///
+/// When the user presses `Enter`, the input is parsed, and the parsed value is passed
+/// to the function. If the input is incorrect, the popup stays open. The user may press a back key
+/// of the [`KeyMap`](crate::tui::KeyMap), `Esc` by default, to close the popup without any value,
+/// and a quit key to close the menu. The characters are always typed in the popup.
+///
+/// See [`TuiKind::Prompt`](crate::tui::TuiKind::Prompt) for more information.
+pub struct TuiPrompt<'a, B: Backend> {
+    written: &'a Written<'a>,
+    handler: Handler<'a, B>,
+}
+
+impl<B: Backend> fmt::Debug for TuiPrompt<'_, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TuiPrompt")
+            .field("written", self.written)
+            .finish()
+    }
+}
+
+impl<'a, B: Backend> TuiPrompt<'a, B> {
+    /// Returns the prompt of the written field, calling the given function with the parsed value.
+    ///
+    /// The input is parsed like with the [`Written::optional_value`] function: the default
+    /// value of the field is used if the input is incorrect.
+    ///
+    /// # Panics
+    ///
+    /// If the default value has an incorrect type, submitting an input may panic.
+    pub fn new<T, F>(written: &'a Written<'a>, f: F) -> Self
+    where
+        T: FromStr,
+        F: Fn(&mut Terminal<B>, T) -> MenuResult + 'a,
+    {
+        Self {
+            written,
+            handler: Box::new(move |term, input| match written.check_default::<T>() {
+                Ok(()) => written
+                    .parse_input(input.trim())
+                    .map(|value| f(term, value)),
+                Err(e) => Some(Err(e)),
+            }),
+        }
+    }
+
+    /// Handles the input submitted by the user.
+    ///
+    /// It returns `None` if the input is incorrect, otherwise the result of the function.
+    pub(crate) fn submit(&self, term: &mut Terminal<B>, input: &str) -> Option<MenuResult> {
+        (self.handler)(term, input)
+    }
+
+    /// Returns the message of the field with its details, displayed as the title of the popup.
+    pub(crate) fn title(&self) -> String {
+        let mut s = String::new();
+        let fmt = Format {
+            prefix: "",
+            line_brk: false,
+            ..self.written.fmt.clone()
+        };
+        // Writing to a string never fails.
+        let _ = self.written.fmt_with(&mut s, &fmt, false);
+        s
+    }
+}
+
+/// The popup displayed when prompting a written field.
+pub(crate) struct PromptWidget<'a> {
+    pub(crate) block: Block<'a>,
+    pub(crate) title: &'a str,
+    pub(crate) input: &'a str,
+    pub(crate) style: Style,
+}
+
+impl Widget for PromptWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.block.render(area, buf);
+
+        // The popup is centered, with a single line for the input.
+        let width = (self.title.chars().count() as u16 + 4)
+            .max(area.width / 2)
+            .min(area.width);
+        let popup = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + area.height.saturating_sub(3) / 2,
+            width,
+            3.min(area.height),
+        );
+
+        Clear.render(popup, buf);
+        Paragraph::new(format!("{}_", self.input))
+            .style(self.style)
+            .block(Block::default().borders(Borders::all()).title(self.title))
+            .render(popup, buf);
+    }
+}
@@ -35,6 +35,7 @@ impl From<TEvent> for Event {
                 TKey::Insert => Key(Insert),
                 TKey::F(x) => Key(F(x)),
                 TKey::Char('\n') => Key(Enter),
+                TKey::Char('\t') => Key(Tab),
                 TKey::Char(x) => Key(Char(x)),
                 TKey::Alt(x) => Key(Alt(x)),
                 TKey::Ctrl(x) => Key(Ctrl(x)),
@@ -43,14 +44,20 @@ impl From<TEvent> for Event {
                 _ => Key(Null),
             },
             TEvent::Mouse(m) => match m {
-                TMouseEvent::Press(b, x, y) => match b {
-                    TMouseButton::Left => Mouse(MouseEvent::Down(MouseButton::Left, x, y)),
-                    TMouseButton::Right => Mouse(MouseEvent::Down(MouseButton::Right, x, y)),
-                    TMouseButton::Middle => Mouse(MouseEvent::Down(MouseButton::Middle, x, y)),
-                    TMouseButton::WheelUp => Mouse(MouseEvent::ScrollUp),
-                    TMouseButton::WheelDown => Mouse(MouseEvent::ScrollDown),
-                },
-                TMouseEvent::Release(x, y) => Mouse(MouseEvent::Up(x, y)),
+                // The termion positions are one-based.
+                TMouseEvent::Press(b, x, y) => {
+                    let (x, y) = (x.saturating_sub(1), y.saturating_sub(1));
+                    match b {
+                        TMouseButton::Left => Mouse(MouseEvent::Down(MouseButton::Left, x, y)),
+                        TMouseButton::Right => Mouse(MouseEvent::Down(MouseButton::Right, x, y)),
+                        TMouseButton::Middle => Mouse(MouseEvent::Down(MouseButton::Middle, x, y)),
+                        TMouseButton::WheelUp => Mouse(MouseEvent::ScrollUp),
+                        TMouseButton::WheelDown => Mouse(MouseEvent::ScrollDown),
+                    }
+                }
+                TMouseEvent::Release(x, y) => {
+                    Mouse(MouseEvent::Up(x.saturating_sub(1), y.saturating_sub(1)))
+                }
                 _ => Key(Null),
             },
             _ => Key(Null),
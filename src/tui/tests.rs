@@ -0,0 +1,598 @@
+use super::{
+    default_styles, event::*, Command, FieldStyle, MenuLayout, MenuWidget, Reader, RunParams,
+    Visit, WidgetField,
+};
+use std::{
+    collections::{HashMap, VecDeque},
+    io::{self, Write},
+    ops::Range,
+};
+use tui::{
+    backend::TestBackend,
+    buffer::Buffer,
+    layout::Rect,
+    widgets::{Block, Borders, Widget},
+    Terminal,
+};
+
+/// Returns no event, when the menu must not wait for the user.
+fn no_event() -> io::Result<Event> {
+    Err(io::ErrorKind::UnexpectedEof.into())
+}
+
+/// Owns the values borrowed by the parameters of a menu run in a test backend.
+struct Fixture {
+    term: Terminal<TestBackend>,
+    area: Rect,
+    styles: (FieldStyle, FieldStyle),
+    read_fn: Reader,
+    layout: MenuLayout,
+    keys: KeyMap,
+    descriptions: HashMap<String, &'static str>,
+    visit: Visit,
+    commands: VecDeque<Command>,
+    announced: Option<Vec<u8>>,
+    #[cfg(feature = "crossterm")]
+    wait: crate::utils::Wait,
+}
+
+impl Fixture {
+    fn new(width: u16, height: u16) -> io::Result<Self> {
+        Ok(Self {
+            term: Terminal::new(TestBackend::new(width, height))?,
+            area: Rect::new(0, 0, width, height),
+            styles: default_styles(),
+            read_fn: no_event,
+            layout: MenuLayout::Vertical,
+            keys: KeyMap::default(),
+            descriptions: HashMap::new(),
+            visit: Visit::default(),
+            commands: VecDeque::new(),
+            announced: None,
+            #[cfg(feature = "crossterm")]
+            wait: Default::default(),
+        })
+    }
+
+    fn params(&mut self) -> RunParams<'_, TestBackend> {
+        RunParams {
+            term: &mut self.term,
+            area: self.area,
+            s_style: &self.styles.0,
+            f_style: &self.styles.1,
+            read_fn: self.read_fn,
+            once: false,
+            wrap: true,
+            layout: self.layout,
+            keys: &self.keys,
+            help: None,
+            descriptions: &self.descriptions,
+            path: Vec::new(),
+            visit: &mut self.visit,
+            commands: &mut self.commands,
+            announcer: self.announced.as_mut().map(|out| out as &mut dyn Write),
+            #[cfg(feature = "crossterm")]
+            wait: self.wait,
+        }
+    }
+}
+
+/// Returns the bordered widget of the given fields, without any style or icon.
+fn widget<'a>(msgs: &[&'a str], styles: &'a (FieldStyle, FieldStyle)) -> MenuWidget<'a> {
+    MenuWidget {
+        fields: msgs
+            .iter()
+            .map(|&msg| WidgetField {
+                msg,
+                icon: None,
+                style: None,
+            })
+            .collect(),
+        block: Block::default().borders(Borders::all()),
+        s_style: &styles.0,
+        f_style: &styles.1,
+        selected: 0,
+        columns: 1,
+        description: None,
+        help: None,
+    }
+}
+
+/// Renders the widget in a buffer of the given size.
+fn render(widget: MenuWidget, width: u16, height: u16) -> Buffer {
+    let area = Rect::new(0, 0, width, height);
+    let mut buf = Buffer::empty(area);
+    widget.render(area, &mut buf);
+    buf
+}
+
+/// Returns the symbols of each row of the buffer, between the given columns.
+fn rows(buf: &Buffer, xs: Range<u16>) -> Vec<String> {
+    (0..buf.area.height)
+        .map(|y| xs.clone().map(|x| buf.get(x, y).symbol.as_str()).collect())
+        .collect()
+}
+
+#[test]
+fn key_actions() {
+    use KeyEvent::*;
+    let keys = KeyMap::default();
+
+    assert_eq!(Action::from_key(Char('q'), &keys), Some(Action::Quit));
+    assert_eq!(Action::from_key(Ctrl('c'), &keys), Some(Action::Quit));
+    assert_eq!(Action::from_key(Ctrl('d'), &keys), Some(Action::Quit));
+    assert_eq!(Action::from_key(Esc, &keys), Some(Action::Back));
+    assert_eq!(Action::from_key(Up, &keys), Some(Action::Up));
+    assert_eq!(Action::from_key(Down, &keys), Some(Action::Down));
+    assert_eq!(Action::from_key(Left, &keys), Some(Action::Prev));
+    assert_eq!(Action::from_key(BackTab, &keys), Some(Action::Prev));
+    assert_eq!(Action::from_key(Tab, &keys), Some(Action::Next));
+    assert_eq!(Action::from_key(Home, &keys), Some(Action::First));
+    assert_eq!(Action::from_key(End, &keys), Some(Action::Last));
+    assert_eq!(Action::from_key(Enter, &keys), Some(Action::Select));
+    assert_eq!(Action::from_key(Char(' '), &keys), Some(Action::Select));
+    assert_eq!(Action::from_key(Char('a'), &keys), None);
+}
+
+#[test]
+fn key_map() {
+    use KeyEvent::*;
+    let keys = KeyMap::new().quit_keys([Esc]).back_keys([Backspace]);
+
+    assert_eq!(Action::from_key(Esc, &keys), Some(Action::Quit));
+    assert_eq!(Action::from_key(Backspace, &keys), Some(Action::Back));
+    assert_eq!(Action::from_key(Char('q'), &keys), None);
+    assert_eq!(Action::from_key(Up, &keys), Some(Action::Up));
+
+    let keys = KeyMap::new().no_quit();
+    assert_eq!(Action::from_key(Char('q'), &keys), None);
+    assert_eq!(Action::from_key(Ctrl('c'), &keys), None);
+    assert_eq!(Action::from_key(Esc, &keys), Some(Action::Back));
+
+    let keys = KeyMap::new().vim().select_keys([Enter]);
+    assert_eq!(Action::from_key(Char('k'), &keys), Some(Action::Up));
+    assert_eq!(Action::from_key(Char('j'), &keys), Some(Action::Down));
+    assert_eq!(Action::from_key(Char('h'), &keys), Some(Action::Prev));
+    assert_eq!(Action::from_key(Char('l'), &keys), Some(Action::Next));
+    assert_eq!(Action::from_key(Char('G'), &keys), Some(Action::Last));
+    assert_eq!(Action::from_key(Up, &keys), Some(Action::Up));
+    assert_eq!(Action::from_key(Char(' '), &keys), None);
+    assert_eq!(Action::from_key(Enter, &keys), Some(Action::Select));
+}
+
+#[cfg(feature = "crossterm")]
+#[test]
+fn crossterm_modifiers() {
+    use crossterm::event::{Event as CTEvent, KeyCode, KeyEvent as CTKeyEvent, KeyModifiers};
+
+    let key = |code, modifiers| match Event::from(CTEvent::Key(CTKeyEvent { code, modifiers })) {
+        Event::Key(k) => k,
+        e => panic!("expected a key event, got {:?}", e),
+    };
+
+    assert!(matches!(
+        key(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT
+        ),
+        KeyEvent::Ctrl('c')
+    ));
+    assert!(matches!(
+        key(KeyCode::Char('x'), KeyModifiers::ALT | KeyModifiers::SHIFT),
+        KeyEvent::Alt('x')
+    ));
+    assert!(matches!(
+        key(KeyCode::Char('Q'), KeyModifiers::SHIFT),
+        KeyEvent::Char('Q')
+    ));
+}
+
+#[test]
+fn mouse_actions() {
+    use tui::layout::Rect;
+    use MouseEvent::*;
+
+    // The block has its borders on the first and last rows and columns.
+    let area = Rect::new(2, 1, 20, 6);
+    let click = |x, y| Action::from_mouse(Down(MouseButton::Left, x, y), area, 3, 1);
+
+    assert_eq!(click(5, 2), Some(Action::SelectAt(0)));
+    assert_eq!(click(5, 4), Some(Action::SelectAt(2)));
+    assert_eq!(click(5, 5), None);
+    assert_eq!(click(5, 1), None);
+    assert_eq!(click(2, 3), None);
+    assert_eq!(click(21, 3), None);
+    assert_eq!(
+        Action::from_mouse(Down(MouseButton::Right, 5, 2), area, 3, 1),
+        None
+    );
+    assert_eq!(Action::from_mouse(ScrollUp, area, 3, 1), Some(Action::Prev));
+    assert_eq!(
+        Action::from_mouse(ScrollDown, area, 3, 1),
+        Some(Action::Next)
+    );
+
+    // In a grid of 2 columns, each field takes the half of the width inside the borders.
+    let click = |x, y| Action::from_mouse(Down(MouseButton::Left, x, y), area, 3, 2);
+    assert_eq!(click(5, 2), Some(Action::SelectAt(0)));
+    assert_eq!(click(15, 2), Some(Action::SelectAt(1)));
+    assert_eq!(click(5, 3), Some(Action::SelectAt(2)));
+    assert_eq!(click(15, 3), None);
+}
+
+#[test]
+fn files_submit() -> Result<(), Box<dyn std::error::Error>> {
+    use super::{run_files, TuiFilePicker};
+    use crate::{field::FilePicker, utils::Depth};
+    use std::{
+        cell::RefCell,
+        fs,
+        path::PathBuf,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    // Moves down to the file, then selects it.
+    fn keys() -> io::Result<Event> {
+        static PRESSED: AtomicUsize = AtomicUsize::new(0);
+        Ok(Event::Key(match PRESSED.fetch_add(1, Ordering::Relaxed) {
+            0 => KeyEvent::Down,
+            _ => KeyEvent::Enter,
+        }))
+    }
+
+    let dir = std::env::temp_dir().join(format!("ezmenulib_files_submit_{}", std::process::id()));
+    fs::create_dir_all(&dir)?;
+    fs::write(dir.join("config.toml"), "")?;
+
+    let picker = FilePicker::from("config").dir(&dir);
+    let out = RefCell::new(PathBuf::new());
+    let files = TuiFilePicker::new(&picker, |_, path| {
+        *out.borrow_mut() = path;
+        Ok(())
+    });
+    let mut fx = Fixture::new(20, 5)?;
+    fx.read_fn = keys;
+    let depth = run_files(&mut fx.params(), &Block::default(), &files);
+
+    let expected = dir.canonicalize()?.join("config.toml");
+    fs::remove_dir_all(&dir)?;
+    assert!(matches!(depth?, Depth::Current));
+    assert_eq!(*out.borrow(), expected);
+    Ok(())
+}
+
+#[test]
+fn prompt_submit() -> Result<(), Box<dyn std::error::Error>> {
+    use super::TuiPrompt;
+    use crate::field::Written;
+    use std::cell::Cell;
+    use tui::{backend::TestBackend, Terminal};
+
+    let age = Written::from("age");
+    let out = Cell::new(0);
+    let prompt = TuiPrompt::new(&age, |_, n: u8| {
+        out.set(n);
+        Ok(())
+    });
+    let mut term = Terminal::new(TestBackend::new(20, 5))?;
+
+    assert!(prompt.submit(&mut term, "abc").is_none());
+    assert!(prompt.submit(&mut term, "19").is_some());
+    assert_eq!(out.get(), 19);
+    assert_eq!(prompt.title(), "age");
+    Ok(())
+}
+
+#[test]
+fn prompt_keys() -> Result<(), Box<dyn std::error::Error>> {
+    use super::{run_prompt, TuiPrompt};
+    use crate::{field::Written, utils::Depth};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // Presses Ctrl-C, then Esc.
+    fn keys() -> io::Result<Event> {
+        static PRESSED: AtomicUsize = AtomicUsize::new(0);
+        Ok(Event::Key(match PRESSED.fetch_add(1, Ordering::Relaxed) {
+            0 => KeyEvent::Ctrl('c'),
+            _ => KeyEvent::Esc,
+        }))
+    }
+
+    let age = Written::from("age");
+    let prompt = TuiPrompt::new(&age, |_, _: u8| Ok(()));
+    let mut fx = Fixture::new(20, 5)?;
+    fx.read_fn = keys;
+    fx.keys = KeyMap::default().no_quit();
+
+    // Without any quit key, Ctrl-C doesn't close the menu.
+    let depth = run_prompt(&mut fx.params(), &Block::default(), &prompt)?;
+    assert!(matches!(depth, Depth::Current));
+    assert_eq!(
+        Action::from_popup_key(KeyEvent::Ctrl('c'), &KeyMap::default()),
+        Some(Action::Quit)
+    );
+    assert_eq!(
+        Action::from_popup_key(KeyEvent::Char('q'), &KeyMap::default()),
+        None
+    );
+    Ok(())
+}
+
+#[cfg(feature = "crossterm")]
+#[test]
+fn menu_timeout() -> Result<(), Box<dyn std::error::Error>> {
+    use super::{run_with, TuiKind};
+    use crate::{tui_fields, utils::Wait, MenuError};
+    use std::time::Duration;
+
+    // The deadline has already elapsed, so the terminal is not polled.
+    let mut fx = Fixture::new(20, 5)?;
+    fx.wait = Wait::timeout(Some(Duration::ZERO));
+    let res = run_with(
+        &mut fx.params(),
+        &Block::default(),
+        tui_fields![("a", TuiKind::Quit)],
+    );
+    assert!(matches!(res, Err(MenuError::Timeout)));
+    Ok(())
+}
+
+#[test]
+fn description_render() {
+    let styles = default_styles();
+    let buf = render(
+        MenuWidget {
+            description: Some("Opens the last project"),
+            ..widget(&["open", "quit"], &styles)
+        },
+        16,
+        7,
+    );
+
+    let row = rows(&buf, 2..14);
+    assert_eq!(row[1], "open        ");
+    assert_eq!(row[2], "quit        ");
+    assert_eq!(row[3], "            ");
+    assert_eq!(row[4], "Opens the   ");
+    assert_eq!(row[5], "last project");
+}
+
+#[test]
+fn field_style_render() {
+    use tui::style::{Color, Style};
+
+    let styles = default_styles();
+    let buf = render(
+        MenuWidget {
+            fields: vec![
+                WidgetField {
+                    msg: "settings",
+                    icon: Some(">"),
+                    style: None,
+                },
+                WidgetField {
+                    msg: "reset",
+                    icon: None,
+                    style: Some(Style::default().fg(Color::Red)),
+                },
+            ],
+            ..widget(&[], &styles)
+        },
+        16,
+        4,
+    );
+
+    let row = rows(&buf, 2..14);
+    assert_eq!(row[1], "> settings  ");
+    assert_eq!(row[2], "reset       ");
+    assert_eq!(buf.get(2, 1).fg, Color::White);
+    assert_eq!(buf.get(2, 2).fg, Color::Red);
+    assert_eq!(buf.get(2, 2).bg, Color::White);
+}
+
+#[test]
+fn help_line() {
+    use KeyEvent::*;
+
+    let hints = KeyMap::default().hints(MenuLayout::Vertical);
+    assert_eq!(hints, "↑/↓ move · Enter select · Esc back · q quit");
+    assert_eq!(
+        KeyMap::new()
+            .up_keys([Char('k')])
+            .down_keys([Char('j')])
+            .no_quit()
+            .hints(MenuLayout::Vertical),
+        "k/j move · Enter select · Esc back"
+    );
+    assert_eq!(
+        KeyMap::new().hints(MenuLayout::Horizontal),
+        "←/→ move · Enter select · Esc back · q quit"
+    );
+
+    let styles = default_styles();
+    let buf = render(
+        MenuWidget {
+            help: Some(&hints),
+            ..widget(&["open"], &styles)
+        },
+        48,
+        5,
+    );
+
+    let row = rows(&buf, 2..46);
+    assert_eq!(row[2], " ".repeat(44));
+    assert_eq!(row[3].trim_end(), hints);
+}
+
+#[test]
+fn field_style_show() -> Result<(), Box<dyn std::error::Error>> {
+    use super::{show_menu, TuiField, TuiKind};
+    use tui::style::{Color, Style};
+
+    let mut fx = Fixture::new(16, 4)?;
+
+    // The fields converted from tuples have no style nor icon.
+    let fields = [
+        TuiField::new("settings", TuiKind::Quit).icon(">"),
+        TuiField::from(("reset", TuiKind::Quit)).style(Style::default().fg(Color::Red)),
+        TuiField::from(("quit", TuiKind::Quit)),
+    ];
+    assert_eq!((fields[2].style, fields[2].icon), (None, None));
+    show_menu(
+        &mut fx.params(),
+        &Block::default().borders(Borders::all()),
+        &fields,
+        0,
+        None,
+    )?;
+
+    let buf = fx.term.backend().buffer();
+    let row = rows(buf, 2..14);
+    assert_eq!(row[1], "> settings  ");
+    assert_eq!(row[2], "reset       ");
+    assert_eq!(buf.get(2, 2).fg, Color::Red);
+    Ok(())
+}
+
+#[test]
+fn announce_moves() -> Result<(), Box<dyn std::error::Error>> {
+    use super::{handle_action, TuiField, TuiKind};
+
+    let mut fx = Fixture::new(20, 5)?;
+    fx.announced = Some(Vec::new());
+    let mut params = fx.params();
+
+    let fields = [
+        TuiField::new("open", TuiKind::Quit),
+        TuiField::new("quit", TuiKind::Quit),
+    ];
+    let mut selected = 0;
+    handle_action(
+        &mut params,
+        &Block::default(),
+        &fields,
+        &mut selected,
+        Some(Action::Next),
+    )?;
+    handle_action(
+        &mut params,
+        &Block::default(),
+        &fields,
+        &mut selected,
+        Some(Action::Next),
+    )?;
+    handle_action(&mut params, &Block::default(), &fields, &mut selected, None)?;
+    drop(params);
+
+    assert_eq!(
+        String::from_utf8(fx.announced.unwrap_or_default())?,
+        "Selected 2 of 2: quit\nSelected 1 of 2: open\n"
+    );
+    Ok(())
+}
+
+#[test]
+fn grid_layout() -> Result<(), Box<dyn std::error::Error>> {
+    use super::{handle_action, TuiField, TuiKind};
+
+    let mut fx = Fixture::new(20, 5)?;
+    fx.layout = MenuLayout::Grid { columns: 3 };
+    let mut params = fx.params();
+
+    // The fields are displayed in 2 rows: a b c / d e
+    let fields = ["a", "b", "c", "d", "e"].map(|msg| TuiField::new(msg, TuiKind::Quit));
+    let mut selected = 1;
+    let mut moves = |action| -> Result<usize, Box<dyn std::error::Error>> {
+        handle_action(
+            &mut params,
+            &Block::default(),
+            &fields,
+            &mut selected,
+            Some(action),
+        )?;
+        Ok(selected)
+    };
+    assert_eq!(moves(Action::Down)?, 4);
+    assert_eq!(moves(Action::Down)?, 1);
+    assert_eq!(moves(Action::Next)?, 2);
+    assert_eq!(moves(Action::Up)?, 2);
+    assert_eq!(moves(Action::Down)?, 2);
+    assert_eq!(moves(Action::Next)?, 3);
+    assert_eq!(moves(Action::Up)?, 0);
+
+    let styles = default_styles();
+    let buf = render(
+        MenuWidget {
+            columns: 3,
+            ..widget(&["a", "b", "c", "d", "e"], &styles)
+        },
+        14,
+        4,
+    );
+
+    let row = rows(&buf, 1..13);
+    assert_eq!(row[1], " a   b   c  ");
+    assert_eq!(row[2], " d   e      ");
+    Ok(())
+}
+#[test]
+fn restore_state() -> Result<(), Box<dyn std::error::Error>> {
+    use super::{run_with, TuiKind};
+    use crate::tui_fields;
+    use crate::{menu::MenuState, utils::Depth};
+
+    fn enter() -> io::Result<Event> {
+        Ok(Event::Key(KeyEvent::Enter))
+    }
+
+    let mut fx = Fixture::new(20, 5)?;
+    fx.read_fn = enter;
+    fx.visit = Visit::new(&"1".parse::<MenuState>()?);
+    let depth = run_with(
+        &mut fx.params(),
+        &Block::default(),
+        tui_fields![
+            ("a", TuiKind::Back(0)),
+            ("sub", TuiKind::Parent(tui_fields![("q", TuiKind::Quit)])),
+        ],
+    )?;
+
+    assert!(matches!(depth, Depth::Quit));
+    assert_eq!(fx.visit.state().path(), &[1]);
+    Ok(())
+}
+
+#[test]
+fn navigation_commands() -> Result<(), Box<dyn std::error::Error>> {
+    use super::{run_with, TuiKind};
+    use crate::tui_fields;
+    use crate::utils::Depth;
+
+    // The commands are applied without reading any event.
+    let mut fx = Fixture::new(20, 5)?;
+    fx.commands = VecDeque::from([
+        Command::Select(1),
+        Command::Enter,
+        Command::Goto("1".parse()?),
+        Command::Back,
+        Command::Select(9),
+        Command::Enter,
+        Command::Select(1),
+        Command::Enter,
+    ]);
+    let depth = run_with(
+        &mut fx.params(),
+        &Block::default(),
+        tui_fields![
+            ("a", TuiKind::Back(0)),
+            (
+                "sub",
+                TuiKind::Parent(tui_fields![("b", TuiKind::Back(0)), ("q", TuiKind::Quit)]),
+            ),
+        ],
+    )?;
+
+    assert!(matches!(depth, Depth::Quit));
+    assert!(fx.commands.is_empty());
+    Ok(())
+}
@@ -1,11 +1,18 @@
 //! This module contains many utils functions used by the library.
 
+use crate::i18n::tr;
 use crate::prelude::*;
 
-use std::any::type_name;
-use std::fmt::Display;
+use std::any::{type_name, Any};
+use std::borrow::Cow;
+use std::fmt::{self, Display};
 use std::io::BufRead;
 use std::io::Write;
+use std::ops::Range;
+use std::thread;
+use std::time::Duration;
+#[cfg(feature = "crossterm")]
+use std::{io, time::Instant};
 
 /// Type to handle the depth of the running menus.
 pub(crate) enum Depth {
@@ -15,6 +22,40 @@ pub(crate) enum Depth {
     Current,
     /// We quit all the nested pages to the top.
     Quit,
+    /// We quit all the nested pages to the top with the value returned by a field.
+    Output(Box<dyn Any>),
+}
+
+/// Logs the error returned by the function mapped to the field with the given message, if any.
+#[cfg_attr(not(feature = "log"), allow(unused_variables))]
+pub(crate) fn logged<T>(res: MenuResult<T>, msg: &str) -> MenuResult<T> {
+    if let Err(e) = &res {
+        log_event!(error, "the field `{}` returned an error: {}", msg, e);
+    }
+    res
+}
+
+/// Returns the depth level resulting from the navigation instruction returned by the function
+/// mapped to a field, in the menu opened by the given parent fields.
+pub(crate) fn navigate(nav: Navigation, path: &[String], once: bool) -> MenuResult<Depth> {
+    Ok(match nav {
+        Navigation::Stay if once => Depth::Quit,
+        Navigation::Stay | Navigation::Back(0) => Depth::Current,
+        Navigation::Back(i) => Depth::Back(i - 1),
+        Navigation::Quit => Depth::Quit,
+        Navigation::Parent(msg) => {
+            // The amount of menus to close, the top-level menu being identified by an empty string.
+            let closed = match path.iter().rposition(|parent| *parent == msg) {
+                Some(i) => path.len() - 1 - i,
+                None if msg.is_empty() => path.len(),
+                None => return Err(format!("there is no parent menu named `{}`", msg).into()),
+            };
+            match closed {
+                0 => Depth::Current,
+                n => Depth::Back(n - 1),
+            }
+        }
+    })
 }
 
 /// Function used by the fields associated functions.
@@ -52,25 +93,482 @@ pub(crate) fn default_failed<T>(default: &str) -> ! {
 }
 
 /// Returns the input value as a String from the given input stream.
+///
+/// It returns a [`MenuError::Cancelled`] error if the end of the stream is reached,
+/// e.g. if the user pressed `Ctrl-D`.
 pub(crate) fn read_input<R: BufRead, W>(stream: &mut MenuStream<R, W>) -> MenuResult<String> {
     let mut out = String::new();
-    stream.read_line(&mut out)?;
+    if stream.read_line(&mut out)? == 0 {
+        return Err(MenuError::Cancelled);
+    }
     Ok(out.trim().to_owned())
 }
 
+/// The input of the user when selecting a value among a list.
+pub(crate) enum Choice {
+    /// The index of the selected value, starting from `0`.
+    Index(usize),
+    /// The user asked for the next page of the list.
+    Next,
+    /// The user asked for the previous page of the list.
+    Prev,
+    /// The user asked for the description of the list, by entering `?`.
+    Help,
+    /// No key has been pressed during the refresh interval, and the values have been erased
+    /// to be displayed again, with the index of the highlighted value.
+    #[cfg(feature = "crossterm")]
+    Refresh(usize),
+    /// No key has been pressed before the timeout elapsed.
+    #[cfg(feature = "crossterm")]
+    Timeout,
+    /// The input is incorrect.
+    Invalid,
+}
+
 /// Prompts the user to enter an index to select a value among the available values.
 ///
 /// The available values are in theory printed before calling this function.
+/// If the list is paged, the user can also ask for the next or the previous page.
+///
+/// The hotkeys are the optional shortcut chars of the values, in the same order.
+/// The index is parsed according to the [index style](Format::index_style) of the format.
 pub(crate) fn select<R: BufRead, W: Write>(
     stream: &mut MenuStream<R, W>,
+    fmt: &Format<'_>,
     suffix: &str,
     max: usize,
-) -> MenuResult<Option<usize>> {
+    paged: bool,
+    hotkeys: &[Option<char>],
+) -> MenuResult<Choice> {
     let s = prompt(suffix, stream)?;
-    Ok(match s.parse::<usize>() {
-        Ok(i) if i >= 1 && i <= max => Some(i - 1),
-        _ => None,
-    })
+    Ok(parse_choice(&s, fmt, max, paged, hotkeys))
+}
+
+/// Lets the user select a value by moving a highlight with the arrow keys,
+/// then pressing `Enter`.
+///
+/// The key events are read from the terminal, and the values are drawn on the given writer.
+/// The highlighted value starts at the `start` index. If a page size is defined, only
+/// a window of the values is drawn around the highlighted value.
+///
+/// The hotkeys select their value immediately. It returns [`Choice::Invalid`]
+/// if the user pressed `Esc`, and a [`MenuError::Cancelled`] error if the user pressed
+/// `Ctrl-C` or `Ctrl-D`. If a refresh interval is given and no key is pressed during
+/// this interval, the values are erased and it returns [`Choice::Refresh`].
+///
+/// If `announce` is true, the highlighted value is described on a line below the values.
+#[cfg(feature = "crossterm")]
+pub(crate) fn select_arrows<W: Write>(
+    writer: &mut W,
+    fmt: &Format<'_>,
+    labels: &[&str],
+    hotkeys: &[Option<char>],
+    start: usize,
+    wait: &mut Wait,
+    announce: bool,
+) -> MenuResult<Choice> {
+    use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+
+    if labels.is_empty() {
+        return Ok(Choice::Invalid);
+    }
+    enable_raw_mode()?;
+    let out = arrows_loop(writer, fmt, labels, hotkeys, start, wait, announce);
+    // The raw mode is disabled even if drawing the values failed.
+    disable_raw_mode()?;
+    out
+}
+
+/// Returns the line entered by the user, or `None` if the user did not press any key
+/// before the deadline.
+///
+/// The keys are read in raw mode until the user starts typing, then the rest of the line
+/// is read from the stream.
+#[cfg(feature = "crossterm")]
+pub(crate) fn read_timed<R: BufRead, W: Write>(
+    stream: &mut MenuStream<R, W>,
+    wait: Wait,
+) -> MenuResult<Option<String>> {
+    use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+
+    // The terminal is not set up if there is no time left.
+    let first = match wait.is_over() {
+        true => None,
+        false => {
+            enable_raw_mode()?;
+            let first = first_keys(stream, wait);
+            // The raw mode is disabled even if reading the keys failed.
+            disable_raw_mode()?;
+            first?
+        }
+    };
+
+    let mut out = match first {
+        Some((typed, false)) => typed,
+        Some((typed, true)) => {
+            writeln!(stream)?;
+            return Ok(Some(typed.trim().to_owned()));
+        }
+        None => {
+            writeln!(stream)?;
+            return Ok(None);
+        }
+    };
+    if stream.read_line(&mut out)? == 0 {
+        return Err(MenuError::Cancelled);
+    }
+    Ok(Some(out.trim().to_owned()))
+}
+
+/// Reads the keys pressed in raw mode before the deadline, then the keys already pressed,
+/// and echoes the typed characters.
+///
+/// It returns the typed characters with true if the user pressed `Enter`,
+/// or `None` if the user did not press any key in time.
+#[cfg(feature = "crossterm")]
+fn first_keys<W: Write>(writer: &mut W, wait: Wait) -> MenuResult<Option<(String, bool)>> {
+    use crossterm::event::{poll, read, Event, KeyCode, KeyEvent, KeyModifiers};
+
+    let mut typed = String::new();
+    let mut pressed = false;
+    loop {
+        // The keys pressed next are read with the rest of the line.
+        let ready = match pressed {
+            true => poll(Duration::ZERO)?,
+            false => wait.poll()?,
+        };
+        if !ready {
+            return Ok(pressed.then_some((typed, false)));
+        }
+
+        if let Event::Key(KeyEvent { code, modifiers }) = read()? {
+            pressed = true;
+            match code {
+                KeyCode::Enter => return Ok(Some((typed, true))),
+                KeyCode::Char('c' | 'd') if modifiers.contains(KeyModifiers::CONTROL) => {
+                    return Err(MenuError::Cancelled)
+                }
+                KeyCode::Char(c) => {
+                    typed.push(c);
+                    write!(writer, "{}", c)?;
+                }
+                KeyCode::Backspace if typed.pop().is_some() => write!(writer, "\x08 \x08")?,
+                _ => (),
+            }
+            writer.flush()?;
+        }
+    }
+}
+
+/// Returns the amount of values drawn at once by the arrow keys selection,
+/// among the given amount of values.
+#[cfg(feature = "crossterm")]
+pub(crate) fn window_size(page_size: Option<usize>, len: usize) -> usize {
+    page_size.map_or(len, |size| size.clamp(1, len))
+}
+
+/// Returns the index of the first value drawn by the arrow keys selection, scrolling
+/// the window of the given size so the highlighted value stays visible.
+#[cfg(feature = "crossterm")]
+pub(crate) fn scroll_offset(offset: usize, current: usize, size: usize) -> usize {
+    offset.min(current).max((current + 1).saturating_sub(size))
+}
+
+/// Defines how long the keys are waited for when reading them with crossterm.
+#[cfg(feature = "crossterm")]
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct Wait {
+    /// The interval after which the values are displayed again, if no key has been pressed.
+    pub(crate) refresh: Option<Duration>,
+    /// The instant after which the prompt gives up, cleared once a key has been pressed.
+    pub(crate) deadline: Option<Instant>,
+}
+
+#[cfg(feature = "crossterm")]
+impl Wait {
+    /// Returns the wait giving up after the given timeout, if any.
+    pub(crate) fn timeout(timeout: Option<Duration>) -> Self {
+        Self {
+            refresh: None,
+            deadline: timeout.map(|t| Instant::now() + t),
+        }
+    }
+
+    /// Returns true if the deadline has elapsed.
+    pub(crate) fn is_over(&self) -> bool {
+        self.deadline.is_some_and(|d| Instant::now() >= d)
+    }
+
+    /// Waits for an event until the refresh interval or the deadline elapses, and returns
+    /// false if there is no event in time.
+    ///
+    /// If there is neither an interval nor a deadline, it returns true without waiting,
+    /// so the next event is read in a blocking way.
+    pub(crate) fn poll(&self) -> io::Result<bool> {
+        use crossterm::event::poll;
+
+        let left = self
+            .deadline
+            .map(|d| d.saturating_duration_since(Instant::now()));
+        match (self.refresh, left) {
+            // The terminal is not polled if the deadline has already elapsed.
+            (_, Some(left)) if left.is_zero() => Ok(false),
+            (Some(interval), Some(left)) => poll(interval.min(left)),
+            (Some(d), None) | (None, Some(d)) => poll(d),
+            (None, None) => Ok(true),
+        }
+    }
+}
+
+#[cfg(feature = "crossterm")]
+fn arrows_loop<W: Write>(
+    writer: &mut W,
+    fmt: &Format<'_>,
+    labels: &[&str],
+    hotkeys: &[Option<char>],
+    start: usize,
+    wait: &mut Wait,
+    announce: bool,
+) -> MenuResult<Choice> {
+    use crossterm::{
+        cursor::MoveUp,
+        event::{read, Event, KeyCode, KeyEvent, KeyModifiers},
+        queue,
+        terminal::{Clear, ClearType},
+    };
+
+    let len = labels.len();
+    let size = window_size(fmt.page_size, len);
+    let marker = fmt.suffix.trim_end();
+    let (mut current, mut offset) = (start.min(len - 1), 0);
+    // The amount of lines drawn, erased before drawing the values again.
+    let lines = size + usize::from(announce);
+
+    loop {
+        offset = scroll_offset(offset, current, size);
+        for (i, label) in labels.iter().enumerate().skip(offset).take(size) {
+            let label = truncate(label, fmt.max_label_width);
+            match i == current {
+                true => write!(writer, "{} {}\r\n", fmt.style.prefix(marker), label)?,
+                false => write!(
+                    writer,
+                    "{:w$} {}\r\n",
+                    "",
+                    label,
+                    w = marker.chars().count()
+                )?,
+            }
+        }
+        if announce {
+            let label = truncate(labels[current], fmt.max_label_width);
+            write!(writer, "Selected {} of {}: {}\r\n", current + 1, len, label)?;
+        }
+        writer.flush()?;
+
+        if !wait.poll()? {
+            if wait.is_over() {
+                return Ok(Choice::Timeout);
+            }
+            queue!(
+                writer,
+                MoveUp(lines as u16),
+                Clear(ClearType::FromCursorDown)
+            )?;
+            return Ok(Choice::Refresh(current));
+        }
+
+        if let Event::Key(KeyEvent { code, modifiers }) = read()? {
+            // The timeout stops once the user pressed a key.
+            wait.deadline = None;
+            match code {
+                KeyCode::Up => current = current.checked_sub(1).unwrap_or(len - 1),
+                KeyCode::Down => current = (current + 1) % len,
+                KeyCode::Home => current = 0,
+                KeyCode::End => current = len - 1,
+                KeyCode::Enter => return Ok(Choice::Index(current)),
+                KeyCode::Esc => return Ok(Choice::Invalid),
+                KeyCode::Char('c' | 'd') if modifiers.contains(KeyModifiers::CONTROL) => {
+                    return Err(MenuError::Cancelled)
+                }
+                KeyCode::Char(c) => {
+                    if let Some(i) = hotkeys.iter().position(|h| *h == Some(c)) {
+                        return Ok(Choice::Index(i));
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        // Erases the values to draw them again.
+        queue!(
+            writer,
+            MoveUp(lines as u16),
+            Clear(ClearType::FromCursorDown)
+        )?;
+    }
+}
+
+/// Returns the choice corresponding to the input of the user when selecting a value.
+///
+/// The hotkeys take precedence over the page controls.
+pub(crate) fn parse_choice(
+    s: &str,
+    fmt: &Format<'_>,
+    max: usize,
+    paged: bool,
+    hotkeys: &[Option<char>],
+) -> Choice {
+    let mut chars = s.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        if let Some(i) = hotkeys.iter().position(|h| *h == Some(c)) {
+            return Choice::Index(i);
+        }
+    }
+
+    let (next, prev) = page_keys(fmt);
+    match s {
+        "next" if paged => Choice::Next,
+        "prev" if paged => Choice::Prev,
+        s if paged && s == next => Choice::Next,
+        s if paged && s == prev => Choice::Prev,
+        "?" => Choice::Help,
+        s => match fmt.parse_index(s, max) {
+            Some(i) => Choice::Index(i),
+            None => Choice::Invalid,
+        },
+    }
+}
+
+/// Returns the inputs changing the page of a list, which are the full words
+/// if the letters are used as indexes.
+fn page_keys(fmt: &Format<'_>) -> (&'static str, &'static str) {
+    match fmt.index_style {
+        IndexStyle::Alphabetic => ("next", "prev"),
+        _ => ("n", "p"),
+    }
+}
+
+/// Handles the pages of a list of values.
+///
+/// If no page size is provided, the list is displayed in a single page.
+pub(crate) struct Pages {
+    size: Option<usize>,
+    len: usize,
+    current: usize,
+}
+
+impl Pages {
+    pub(crate) fn new(size: Option<usize>, len: usize) -> Self {
+        Self {
+            // A page must contain at least one value.
+            size: size.map(|s| s.max(1)),
+            len,
+            current: 0,
+        }
+    }
+
+    /// Returns true if the list is split into several pages.
+    pub(crate) fn is_paged(&self) -> bool {
+        matches!(self.size, Some(size) if size < self.len)
+    }
+
+    /// Returns the range of the indexes of the values displayed in the current page.
+    pub(crate) fn range(&self) -> Range<usize> {
+        match self.size {
+            Some(size) => {
+                let start = self.current * size;
+                start..(start + size).min(self.len)
+            }
+            None => 0..self.len,
+        }
+    }
+
+    /// Goes to the next page, returning true if there is one.
+    pub(crate) fn next(&mut self) -> bool {
+        let has_next = self.range().end < self.len;
+        if has_next {
+            self.current += 1;
+        }
+        has_next
+    }
+
+    /// Goes to the previous page, returning true if there is one.
+    pub(crate) fn prev(&mut self) -> bool {
+        let has_prev = self.current > 0;
+        if has_prev {
+            self.current -= 1;
+        }
+        has_prev
+    }
+
+    /// Displays the controls to move between the pages, according to the given format.
+    pub(crate) fn fmt_controls<S: fmt::Write>(&self, s: &mut S, fmt: &Format<'_>) -> fmt::Result {
+        let (next, prev) = page_keys(fmt);
+        if self.range().end < self.len {
+            let label = tr("ezmenulib.next-page", "next page", &[]);
+            writeln!(s, "{}{}{}", fmt.surround(next), fmt.chip, label)?;
+        }
+        if self.current > 0 {
+            let label = tr("ezmenulib.prev-page", "previous page", &[]);
+            writeln!(s, "{}{}{}", fmt.surround(prev), fmt.chip, label)?;
+        }
+        Ok(())
+    }
+}
+
+/// The input used to show the documentation of a field.
+pub(crate) const DOCS_INPUT: &str = "?!";
+
+/// Prints out the link to the documentation of a field.
+///
+/// If the `"open-docs"` feature is enabled, the link is also opened with the default program
+/// of the system. The link is printed out anyway, in case the program can't be run.
+pub(crate) fn show_docs<W: Write>(writer: &mut W, url: &str) -> MenuResult {
+    #[cfg(feature = "open-docs")]
+    {
+        use std::process::{Command, Stdio};
+
+        let program = if cfg!(target_os = "macos") {
+            "open"
+        } else if cfg!(windows) {
+            "explorer"
+        } else {
+            "xdg-open"
+        };
+        // The link is printed out below if the program fails.
+        let _ = Command::new(program)
+            .arg(url)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn();
+    }
+
+    writeln!(writer, "Documentation: {}", url)?;
+    Ok(())
+}
+
+/// Counts an incorrect input, and returns an error if the maximum amount
+/// of attempts is reached.
+///
+/// If a backoff delay is given, it then waits before the next attempt, doubling the delay
+/// after each incorrect input.
+pub(crate) fn attempt(
+    max: Option<usize>,
+    backoff: Option<Duration>,
+    attempts: &mut usize,
+) -> MenuResult {
+    *attempts += 1;
+    match max {
+        Some(max) if *attempts >= max => Err(MenuError::TooManyAttempts),
+        _ => {
+            if let Some(delay) = backoff {
+                // The exponent is bounded to avoid overflowing the delay.
+                let factor = 1 << (*attempts - 1).min(16);
+                thread::sleep(delay.saturating_mul(factor));
+            }
+            Ok(())
+        }
+    }
 }
 
 /// Checks that the menu fields are not empty at runtime.
@@ -79,3 +577,67 @@ pub(crate) fn check_fields<T>(fields: &[T]) {
         panic!("empty fields for the selectable values");
     }
 }
+
+/// Truncates the given message to the given maximum width, ending it with `…`.
+///
+/// The width corresponds to the amount of chars, so the message is never cut
+/// inside a multi-byte character.
+pub(crate) fn truncate(msg: &str, max: Option<usize>) -> Cow<'_, str> {
+    match max {
+        Some(max) if msg.chars().count() > max => {
+            let mut out: String = msg.chars().take(max.saturating_sub(1)).collect();
+            out.push('…');
+            Cow::Owned(out)
+        }
+        _ => Cow::Borrowed(msg),
+    }
+}
+
+/// Returns the edit distance between the two strings, ignoring the case.
+///
+/// The edits are the insertions, deletions, substitutions and transpositions
+/// of adjacent chars, so that common typos like `ture` for `true` count as one edit.
+fn distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().flat_map(char::to_lowercase).collect();
+    let b: Vec<char> = b.chars().flat_map(char::to_lowercase).collect();
+
+    // `d[i][j]` is the distance between the first `i` chars of `a` and the first `j` chars of `b`.
+    let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    d[a.len()][b.len()]
+}
+
+/// Returns the candidate closest to the given input, if it is close enough to be suggested.
+///
+/// A candidate is suggested if the input differs from it by at most a third of its length,
+/// with at least one edit allowed.
+pub(crate) fn closest<'c>(input: &str, candidates: &[&'c str]) -> Option<&'c str> {
+    if input.is_empty() {
+        return None;
+    }
+
+    candidates
+        .iter()
+        .map(|c| (distance(input, c), *c))
+        .filter(|(d, c)| *d > 0 && *d <= (c.chars().count() / 3).max(1))
+        .min_by_key(|(d, _)| *d)
+        .map(|(_, c)| c)
+}